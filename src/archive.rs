@@ -0,0 +1,485 @@
+//! Channel-wide archive of videos, boards and posts.
+//!
+//! An archive directory looks like this:
+//!
+//! ```text
+//! <dir>/
+//!   manifest.json               progress of the archive, used to resume
+//!   index.json                  written once the archive is complete
+//!   channel.json
+//!   videos/<video_seq>/
+//!     video.json
+//!     thumbnail.jpg
+//!     captions/<locale>.vtt
+//!     video.mp4                 only with `ArchiveOptions::media`
+//!   boards/<board_id>/
+//!     board.json
+//!     posts/<post_id>.json
+//!     photos/
+//! ```
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::download::{download_file, file_exists};
+use crate::error::{Error, Result};
+use crate::model::{
+    board_posts::Post,
+    channel::{Channel, VideoListItem},
+    grouped_board::Board,
+};
+use crate::{photos, ChannelId, VLiveRequester};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const INDEX_FILE: &str = "index.json";
+
+/// What to include in an archive
+#[derive(Debug, Clone)]
+pub struct ArchiveOptions {
+    /// Download video thumbnails
+    pub thumbnails: bool,
+    /// Download video captions
+    pub captions: bool,
+    /// Download photos attached to posts
+    pub photos: bool,
+    /// Download the highest quality mp4 of each video. Videos can be large,
+    /// so this is off by default
+    pub media: bool,
+    /// Number of videos or posts to request per page
+    pub page_size: u32,
+    /// Skip boards that require a VLIVE+ purchase
+    pub skip_paid_boards: bool,
+}
+
+impl Default for ArchiveOptions {
+    fn default() -> Self {
+        Self {
+            thumbnails: true,
+            captions: true,
+            photos: true,
+            media: false,
+            page_size: 50,
+            skip_paid_boards: true,
+        }
+    }
+}
+
+/// Progress of an archive, saved after every video and page of posts so an
+/// interrupted archive continues where it stopped
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Manifest {
+    pub channel_code: String,
+    pub videos: Vec<ArchivedVideo>,
+    /// If every page of the video list has been archived
+    pub videos_complete: bool,
+    pub boards: Vec<BoardProgress>,
+}
+
+impl Manifest {
+    /// Loads the manifest in an archive directory, None if there isn't one
+    pub async fn load<P: AsRef<Path>>(dir: P) -> Result<Option<Self>> {
+        let path = dir.as_ref().join(MANIFEST_FILE);
+
+        if !file_exists(&path).await {
+            return Ok(None);
+        }
+
+        let manifest = serde_json::from_slice(&fs::read(path).await?)?;
+
+        Ok(Some(manifest))
+    }
+
+    /// Checks if every video and board has been archived
+    pub fn is_complete(&self) -> bool {
+        self.videos_complete && self.boards.iter().all(|b| b.complete)
+    }
+
+    async fn save(&self, dir: &Path) -> Result<()> {
+        // Write then rename so an interrupted save doesn't corrupt the manifest
+        let tmp = dir.join(format!("{}.tmp", MANIFEST_FILE));
+        fs::write(&tmp, serde_json::to_vec_pretty(self)?).await?;
+        fs::rename(tmp, dir.join(MANIFEST_FILE)).await?;
+
+        Ok(())
+    }
+
+    fn board_mut(&mut self, board: &Board) -> &mut BoardProgress {
+        let pos = match self
+            .boards
+            .iter()
+            .position(|b| b.board_id == board.board_id)
+        {
+            Some(pos) => pos,
+            None => {
+                self.boards.push(BoardProgress {
+                    board_id: board.board_id,
+                    title: board.title.clone(),
+                    after: None,
+                    complete: false,
+                    posts: Vec::new(),
+                });
+                self.boards.len() - 1
+            }
+        };
+
+        &mut self.boards[pos]
+    }
+}
+
+/// Video saved in an archive
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedVideo {
+    pub video_seq: u32,
+    pub title: String,
+    pub on_air_start_at: DateTime<Utc>,
+    /// Directory of the video, relative to the archive
+    pub path: PathBuf,
+    /// Locales of the downloaded captions
+    pub captions: Vec<String>,
+    pub has_media: bool,
+    /// URLs of thumbnails, captions or media the server refused with a 4xx
+    /// status, these aren't retried when the archive is resumed
+    #[serde(default)]
+    pub failed_downloads: Vec<String>,
+}
+
+/// Archived posts of a board, along with the cursor of the next page
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardProgress {
+    pub board_id: i64,
+    pub title: String,
+    /// Cursor of the next page of posts to archive
+    pub after: Option<String>,
+    /// If every page of posts has been archived
+    pub complete: bool,
+    pub posts: Vec<ArchivedPost>,
+}
+
+/// Post saved in an archive
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedPost {
+    pub post_id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    /// JSON file of the post, relative to the archive
+    pub path: PathBuf,
+    pub photo_count: usize,
+}
+
+/// Index of a completed archive, saved as `index.json`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveIndex {
+    pub channel: Channel,
+    pub archived_at: DateTime<Utc>,
+    pub videos: Vec<ArchivedVideo>,
+    pub boards: Vec<BoardProgress>,
+}
+
+/// Archives every video, board and post of a channel into `dir`.
+///
+/// If `dir` already has a manifest from an interrupted archive, videos and
+/// posts that were already saved are skipped. Videos without streams, such as
+/// live or paid videos, are saved without captions or media. Thumbnails,
+/// captions and media the server refuses with a 4xx status are skipped and
+/// listed in `ArchivedVideo::failed_downloads`.
+///
+/// API requests are made with `requester`, while thumbnails, captions, photos
+/// and media are downloaded with `client`. Both can be the same `Client`.
+pub async fn archive_channel<R, P>(
+    requester: &R,
+    client: &Client,
+    channel: &ChannelId,
+    dir: P,
+    options: &ArchiveOptions,
+) -> Result<ArchiveIndex>
+where
+    R: VLiveRequester + Sync + ?Sized,
+    P: AsRef<Path>,
+{
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir).await?;
+
+    let channel_code = channel.code(requester).await?;
+    let mut manifest = match Manifest::load(dir).await? {
+        Some(m) if m.channel_code == channel_code => m,
+        Some(m) => {
+            return Err(Error::Vlive(format!(
+                "Archive directory belongs to channel {}, not {}",
+                m.channel_code, channel_code
+            )))
+        }
+        None => Manifest {
+            channel_code: channel_code.to_string(),
+            ..Default::default()
+        },
+    };
+
+    let channel_info = requester.get_channel_info(channel).await?;
+    write_json(&dir.join("channel.json"), &channel_info).await?;
+
+    if !manifest.videos_complete {
+        archive_videos(requester, client, channel, dir, options, &mut manifest).await?;
+    }
+
+    archive_boards(requester, client, channel, dir, options, &mut manifest).await?;
+
+    let index = ArchiveIndex {
+        channel: channel_info,
+        archived_at: Utc::now(),
+        videos: manifest.videos,
+        boards: manifest.boards,
+    };
+    write_json(&dir.join(INDEX_FILE), &index).await?;
+
+    Ok(index)
+}
+
+async fn archive_videos<R>(
+    requester: &R,
+    client: &Client,
+    channel: &ChannelId,
+    dir: &Path,
+    options: &ArchiveOptions,
+    manifest: &mut Manifest,
+) -> Result<()>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    // Pages shift as new videos are uploaded, so always start from the first
+    // page and skip videos that are already archived
+    let mut done: HashSet<u32> = manifest.videos.iter().map(|v| v.video_seq).collect();
+    let mut page_no = 1;
+    let mut seen = 0;
+
+    loop {
+        let list = requester
+            .get_channel_video_list(channel, options.page_size, page_no)
+            .await?;
+
+        if list.video_list.is_empty() {
+            break;
+        }
+
+        seen += list.video_list.len() as u32;
+
+        for item in &list.video_list {
+            if !done.insert(item.video_seq) {
+                continue;
+            }
+
+            let video = archive_video(requester, client, item, dir, options).await?;
+            manifest.videos.push(video);
+            manifest.save(dir).await?;
+        }
+
+        if seen >= list.total_video_count {
+            break;
+        }
+
+        page_no += 1;
+    }
+
+    manifest.videos_complete = true;
+    manifest.save(dir).await
+}
+
+async fn archive_video<R>(
+    requester: &R,
+    client: &Client,
+    item: &VideoListItem,
+    dir: &Path,
+    options: &ArchiveOptions,
+) -> Result<ArchivedVideo>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let rel_path = Path::new("videos").join(item.video_seq.to_string());
+    let video_dir = dir.join(&rel_path);
+    fs::create_dir_all(&video_dir).await?;
+
+    write_json(&video_dir.join("video.json"), item).await?;
+
+    let mut archived = ArchivedVideo {
+        video_seq: item.video_seq,
+        title: item.title.clone(),
+        on_air_start_at: item.on_air_start_at,
+        path: rel_path,
+        captions: Vec::new(),
+        has_media: false,
+        failed_downloads: Vec::new(),
+    };
+
+    if options.thumbnails && !item.thumbnail.is_empty() {
+        let path = video_dir.join("thumbnail.jpg");
+        download_asset(client, &item.thumbnail, &path, &mut archived).await?;
+    }
+
+    if !options.captions && !options.media {
+        return Ok(archived);
+    }
+
+    if item.is_paid() {
+        tracing::debug!(
+            video_seq = item.video_seq,
+            "Paid video, skipping captions and media"
+        );
+        return Ok(archived);
+    }
+
+    // Other errors aren't recorded in the manifest, so the video is retried
+    // when the archive is resumed
+    let streams = match requester.get_video_streams(item.video_seq as u64).await {
+        Ok(streams) => streams,
+        Err(e @ Error::IsLive) | Err(e @ Error::MissingDetails) => {
+            tracing::warn!(video_seq = item.video_seq, error = %e, "No streams for video, skipping captions and media");
+            return Ok(archived);
+        }
+        Err(e) => return Err(e),
+    };
+
+    if options.captions {
+        if let Some(captions) = &streams.captions {
+            let captions_dir = video_dir.join("captions");
+            fs::create_dir_all(&captions_dir).await?;
+
+            for caption in &captions.list {
+                let path = captions_dir.join(format!("{}.vtt", caption.locale));
+                if download_asset(client, &caption.source, &path, &mut archived).await? {
+                    archived.captions.push(caption.locale.clone());
+                }
+            }
+        }
+    }
+
+    if options.media {
+        if let Some(best) = streams.videos.list.iter().max_by_key(|v| v.size) {
+            let path = video_dir.join("video.mp4");
+            archived.has_media = download_asset(client, &best.source, &path, &mut archived).await?;
+        }
+    }
+
+    Ok(archived)
+}
+
+/// Downloads a thumbnail, caption or media file of a video, returning if it
+/// was saved. Files the server refuses with a 4xx status, such as expired
+/// captions, are added to `failed_downloads` instead of stopping the archive
+async fn download_asset(
+    client: &Client,
+    url: &str,
+    path: &Path,
+    archived: &mut ArchivedVideo,
+) -> Result<bool> {
+    match download_file(client, url, path).await {
+        Ok(()) => Ok(true),
+        Err(Error::Reqwest(e)) if matches!(e.status(), Some(s) if s.is_client_error()) => {
+            tracing::warn!(video_seq = archived.video_seq, url, error = %e, "Skipping missing file");
+            archived.failed_downloads.push(url.to_string());
+            Ok(false)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+async fn archive_boards<R>(
+    requester: &R,
+    client: &Client,
+    channel: &ChannelId,
+    dir: &Path,
+    options: &ArchiveOptions,
+    manifest: &mut Manifest,
+) -> Result<()>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let grouped_boards = requester.get_channel_grouped_boards(channel).await?;
+
+    for board in grouped_boards.iter().flat_map(|g| g.boards.iter()) {
+        if options.skip_paid_boards && board.is_paid() {
+            tracing::debug!(board_id = board.board_id, "Skipping paid board");
+            continue;
+        }
+
+        if manifest.board_mut(board).complete {
+            continue;
+        }
+
+        let board_dir = dir.join("boards").join(board.board_id.to_string());
+        fs::create_dir_all(board_dir.join("posts")).await?;
+        write_json(&board_dir.join("board.json"), board).await?;
+
+        loop {
+            let after = manifest.board_mut(board).after.clone();
+            let posts = requester
+                .get_board_posts_page(
+                    channel,
+                    board.board_id as u64,
+                    options.page_size,
+                    after.as_deref(),
+                )
+                .await?;
+
+            let mut archived = Vec::with_capacity(posts.data.len());
+
+            for post in &posts.data {
+                archived.push(archive_post(client, post, dir, &board_dir, options).await?);
+            }
+
+            let progress = manifest.board_mut(board);
+            // Posts from a page that was interrupted are saved again on resume
+            let ids: HashSet<_> = archived.iter().map(|p| p.post_id.clone()).collect();
+            progress.posts.retain(|p| !ids.contains(&p.post_id));
+            progress.posts.extend(archived);
+            progress.after = posts.next_after().map(|s| s.to_string());
+            progress.complete = progress.after.is_none() || posts.data.is_empty();
+            let complete = progress.complete;
+
+            manifest.save(dir).await?;
+
+            if complete {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn archive_post(
+    client: &Client,
+    post: &Post,
+    dir: &Path,
+    board_dir: &Path,
+    options: &ArchiveOptions,
+) -> Result<ArchivedPost> {
+    let path = board_dir
+        .join("posts")
+        .join(format!("{}.json", post.post_id));
+    write_json(&path, post).await?;
+
+    if options.photos && !post.attachments.photo.is_empty() {
+        photos::download_post_photos(client, post, board_dir.join("photos")).await?;
+    }
+
+    Ok(ArchivedPost {
+        post_id: post.post_id.clone(),
+        title: post.title.clone(),
+        created_at: post.created_at,
+        path: path.strip_prefix(dir).unwrap_or(&path).to_path_buf(),
+        photo_count: post.attachments.photo.len(),
+    })
+}
+
+async fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    fs::write(path, serde_json::to_vec_pretty(value)?).await?;
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::OnceLock;
+
+use crate::error::{Error, Result};
+use crate::VLiveRequester;
+
+/// Identifies a channel by either its code (`FE619`) or seq (`13`).
+///
+/// Some endpoints use the channel code and others use the channel seq, so
+/// the missing form is resolved on first use and cached for later requests.
+/// Reuse the same `ChannelId` across requests to avoid resolving it again.
+#[derive(Debug, Clone)]
+pub struct ChannelId {
+    code: OnceLock<String>,
+    seq: OnceLock<u32>,
+}
+
+impl ChannelId {
+    /// Creates a channel ID from a channel code, such as `FE619`
+    pub fn from_code<S: Into<String>>(code: S) -> Self {
+        Self {
+            code: OnceLock::from(code.into()),
+            seq: OnceLock::new(),
+        }
+    }
+
+    /// Creates a channel ID from a channel seq, such as `13`
+    pub fn from_seq(seq: u32) -> Self {
+        Self {
+            code: OnceLock::new(),
+            seq: OnceLock::from(seq),
+        }
+    }
+
+    /// Creates a channel ID when both forms are already known
+    pub fn new<S: Into<String>>(code: S, seq: u32) -> Self {
+        Self {
+            code: OnceLock::from(code.into()),
+            seq: OnceLock::from(seq),
+        }
+    }
+
+    /// Channel code if it is known without making any requests
+    pub fn cached_code(&self) -> Option<&str> {
+        self.code.get().map(String::as_str)
+    }
+
+    /// Channel seq if it is known without making any requests
+    pub fn cached_seq(&self) -> Option<u32> {
+        self.seq.get().copied()
+    }
+
+    /// Gets the channel code, fetching the channel's video list to find it
+    /// if only the seq is known
+    pub async fn code<R>(&self, requester: &R) -> Result<&str>
+    where
+        R: VLiveRequester + Sync + ?Sized,
+    {
+        if let Some(code) = self.cached_code() {
+            return Ok(code);
+        }
+
+        let seq = self
+            .cached_seq()
+            .ok_or_else(|| Error::from("Channel ID has no code or seq"))?;
+        let video_list = requester
+            .get_channel_video_list(&ChannelId::from_seq(seq), 1, 1)
+            .await?;
+
+        // Ignore error if it was set by a concurrent request in the meantime
+        let _ = self.code.set(video_list.channel_info.channel_code);
+
+        self.cached_code()
+            .ok_or_else(|| Error::from("Failed to resolve channel code"))
+    }
+
+    /// Gets the channel seq, decoding the channel code if only the code is known
+    pub async fn seq<R>(&self, requester: &R) -> Result<u32>
+    where
+        R: VLiveRequester + Sync + ?Sized,
+    {
+        if let Some(seq) = self.cached_seq() {
+            return Ok(seq);
+        }
+
+        let code = self
+            .cached_code()
+            .ok_or_else(|| Error::from("Channel ID has no code or seq"))?;
+        let seq = requester.decode_channel_code(code).await?;
+        let _ = self.seq.set(seq);
+
+        Ok(seq)
+    }
+}
+
+impl From<&str> for ChannelId {
+    fn from(code: &str) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl From<String> for ChannelId {
+    fn from(code: String) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl From<u32> for ChannelId {
+    fn from(seq: u32) -> Self {
+        Self::from_seq(seq)
+    }
+}
+
+/// Equality is by the forms that are already known, no requests are made to
+/// resolve the other form. Codes are compared if both IDs have one, otherwise
+/// seqs are compared, so `ChannelId::from_code("FE619")` is not equal to
+/// `ChannelId::from_seq(13)` even though both refer to the same channel.
+///
+/// Since resolving a form can change the result, `ChannelId` doesn't
+/// implement `Eq` or `Hash`. Use the resolved `code` as a map key instead.
+impl PartialEq for ChannelId {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.cached_code(), other.cached_code()) {
+            (Some(a), Some(b)) => a == b,
+            _ => matches!(
+                (self.cached_seq(), other.cached_seq()),
+                (Some(a), Some(b)) if a == b
+            ),
+        }
+    }
+}
+
+impl Display for ChannelId {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match (self.cached_code(), self.cached_seq()) {
+            (Some(code), _) => write!(f, "{}", code),
+            (None, Some(seq)) => write!(f, "{}", seq),
+            (None, None) => write!(f, "unknown channel"),
+        }
+    }
+}
@@ -0,0 +1,465 @@
+//! SQLite persistence for fetched models, enabled with the `sqlite` feature.
+//!
+//! Videos from `get_channel_video_list`, `get_video` and `get_recent_videos`
+//! are merged into a single `videos` table keyed by video seq, so upserting
+//! one model doesn't clear columns set by another. Timestamps are stored as
+//! milliseconds since the Unix epoch, and the full model is kept as JSON in
+//! the `data` columns where available.
+//!
+//! Publish times estimated from `RecentVideo::posted_age` are kept apart in
+//! `approx_published_at`, so `on_air_start_at` is only ever an exact time and
+//! is NULL for videos only seen in recent videos.
+
+use chrono::{DateTime, TimeZone, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::error::Result;
+use crate::model::{
+    board_posts::{BoardPosts, Post},
+    channel::{Channel, ChannelVideoList, VideoListItem},
+    grouped_board::Board,
+    recent_video::RecentVideo,
+    video::OfficialVideo,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS channels (
+    channel_code TEXT PRIMARY KEY,
+    channel_name TEXT NOT NULL,
+    channel_profile_image TEXT,
+    channel_description TEXT,
+    open_at INTEGER,
+    member_count INTEGER,
+    post_count_of_star INTEGER,
+    video_count_of_star INTEGER,
+    video_play_count_of_star INTEGER,
+    video_like_count_of_star INTEGER,
+    video_comment_count_of_star INTEGER,
+    data TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS videos (
+    video_seq INTEGER PRIMARY KEY,
+    channel_code TEXT,
+    title TEXT NOT NULL,
+    video_type TEXT NOT NULL,
+    play_count INTEGER,
+    like_count INTEGER,
+    comment_count INTEGER,
+    thumbnail TEXT,
+    play_time INTEGER,
+    screen_orientation TEXT,
+    product_type TEXT,
+    product_id TEXT,
+    package_product_id TEXT,
+    on_air_start_at INTEGER,
+    approx_published_at INTEGER,
+    created_at INTEGER,
+    list_data TEXT,
+    detail_data TEXT,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS videos_channel_on_air
+    ON videos (channel_code, on_air_start_at);
+
+CREATE TABLE IF NOT EXISTS boards (
+    board_id INTEGER PRIMARY KEY,
+    channel_code TEXT NOT NULL,
+    title TEXT NOT NULL,
+    board_type TEXT NOT NULL,
+    open_type TEXT NOT NULL,
+    pay_required INTEGER NOT NULL,
+    last_updated_at INTEGER NOT NULL,
+    data TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS posts (
+    post_id TEXT PRIMARY KEY,
+    board_id INTEGER NOT NULL,
+    channel_code TEXT NOT NULL,
+    title TEXT NOT NULL,
+    author_member_id TEXT NOT NULL,
+    author_nickname TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    plain_body TEXT NOT NULL,
+    comment_count INTEGER NOT NULL,
+    emotion_count INTEGER NOT NULL,
+    video_seq INTEGER,
+    created_at INTEGER NOT NULL,
+    data TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS posts_board_created
+    ON posts (board_id, created_at);
+";
+
+/// Video row from the `videos` table
+#[derive(Debug, Clone)]
+pub struct StoredVideo {
+    pub video_seq: u64,
+    pub channel_code: Option<String>,
+    pub title: String,
+    pub video_type: String,
+    pub play_count: Option<i64>,
+    pub like_count: Option<i64>,
+    pub comment_count: Option<i64>,
+    pub on_air_start_at: Option<DateTime<Utc>>,
+    /// Estimated from `RecentVideo::posted_age`
+    pub approx_published_at: Option<DateTime<Utc>>,
+}
+
+/// Creates the tables and indexes if they don't exist yet
+pub fn create_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(SCHEMA)?;
+
+    Ok(())
+}
+
+pub fn upsert_channel(conn: &Connection, channel: &Channel) -> Result<()> {
+    conn.execute(
+        "INSERT INTO channels (
+            channel_code, channel_name, channel_profile_image, channel_description,
+            open_at, member_count, post_count_of_star, video_count_of_star,
+            video_play_count_of_star, video_like_count_of_star,
+            video_comment_count_of_star, data, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+        ON CONFLICT (channel_code) DO UPDATE SET
+            channel_name = excluded.channel_name,
+            channel_profile_image = excluded.channel_profile_image,
+            channel_description = excluded.channel_description,
+            open_at = excluded.open_at,
+            member_count = excluded.member_count,
+            post_count_of_star = excluded.post_count_of_star,
+            video_count_of_star = excluded.video_count_of_star,
+            video_play_count_of_star = excluded.video_play_count_of_star,
+            video_like_count_of_star = excluded.video_like_count_of_star,
+            video_comment_count_of_star = excluded.video_comment_count_of_star,
+            data = excluded.data,
+            updated_at = excluded.updated_at",
+        params![
+            channel.channel_code,
+            channel.channel_name,
+            channel.channel_profile_image,
+            channel.channel_description,
+            channel.open_at.map(|t| t.timestamp_millis()),
+            channel.member_count,
+            channel.post_count_of_star,
+            channel.video_count_of_star,
+            channel.video_play_count_of_star,
+            channel.video_like_count_of_star,
+            channel.video_comment_count_of_star,
+            serde_json::to_string(channel)?,
+            now_millis(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Upserts a video from a channel's video list. `VideoListItem` doesn't
+/// include the channel, so it has to be given.
+pub fn upsert_video_list_item(
+    conn: &Connection,
+    channel_code: &str,
+    video: &VideoListItem,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO videos (
+            video_seq, channel_code, title, video_type, play_count, like_count,
+            comment_count, thumbnail, play_time, screen_orientation, product_type,
+            product_id, package_product_id, on_air_start_at, created_at,
+            list_data, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+        ON CONFLICT (video_seq) DO UPDATE SET
+            channel_code = excluded.channel_code,
+            title = excluded.title,
+            video_type = excluded.video_type,
+            play_count = excluded.play_count,
+            like_count = excluded.like_count,
+            comment_count = excluded.comment_count,
+            thumbnail = excluded.thumbnail,
+            play_time = excluded.play_time,
+            screen_orientation = excluded.screen_orientation,
+            product_type = excluded.product_type,
+            product_id = excluded.product_id,
+            package_product_id = excluded.package_product_id,
+            on_air_start_at = excluded.on_air_start_at,
+            created_at = COALESCE(excluded.created_at, videos.created_at),
+            list_data = excluded.list_data,
+            updated_at = excluded.updated_at",
+        params![
+            video.video_seq,
+            channel_code,
+            video.title,
+            video.video_type.as_str(),
+            video.play_count,
+            video.like_count,
+            video.comment_count,
+            video.thumbnail,
+            video.play_time,
+            video.screen_orientation.as_str(),
+            video.product_type.as_str(),
+            video.product_id,
+            video.package_product_id,
+            video.on_air_start_at.timestamp_millis(),
+            video.created_at.map(|t| t.timestamp_millis()),
+            serde_json::to_string(video)?,
+            now_millis(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Upserts every video in a page of a channel's video list, in a single
+/// transaction
+pub fn upsert_video_list(conn: &Connection, list: &ChannelVideoList) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    for video in &list.video_list {
+        upsert_video_list_item(&tx, &list.channel_info.channel_code, video)?;
+    }
+
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Upserts a video from `get_video`. Pass the channel code from the post or
+/// `VideoState::channel` if known.
+pub fn upsert_official_video(
+    conn: &Connection,
+    channel_code: Option<&str>,
+    video: &OfficialVideo,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO videos (
+            video_seq, channel_code, title, video_type, play_count, like_count,
+            comment_count, thumbnail, play_time, screen_orientation, product_type,
+            on_air_start_at, created_at, detail_data, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+        ON CONFLICT (video_seq) DO UPDATE SET
+            channel_code = COALESCE(excluded.channel_code, videos.channel_code),
+            title = excluded.title,
+            video_type = excluded.video_type,
+            play_count = excluded.play_count,
+            like_count = excluded.like_count,
+            comment_count = excluded.comment_count,
+            thumbnail = excluded.thumbnail,
+            play_time = COALESCE(excluded.play_time, videos.play_time),
+            screen_orientation = COALESCE(excluded.screen_orientation, videos.screen_orientation),
+            product_type = COALESCE(excluded.product_type, videos.product_type),
+            on_air_start_at = excluded.on_air_start_at,
+            created_at = excluded.created_at,
+            detail_data = excluded.detail_data,
+            updated_at = excluded.updated_at",
+        params![
+            video.video_seq,
+            channel_code,
+            video.title,
+            video.kind.as_str(),
+            video.play_count,
+            video.like_count,
+            video.comment_count,
+            video.thumb,
+            video.play_time,
+            video.screen_orientation.as_ref().map(|o| o.as_str()),
+            video.product_type.as_ref().map(|p| p.as_str()),
+            video.on_air_start_at.timestamp_millis(),
+            video.created_at.timestamp_millis(),
+            serde_json::to_string(video)?,
+            now_millis(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Upserts a video from `get_recent_videos`. Counts and the publish time are
+/// only set if the page included them, the publish time is approximate so it's
+/// stored as `approx_published_at` rather than `on_air_start_at`.
+pub fn upsert_recent_video(conn: &Connection, video: &RecentVideo) -> Result<()> {
+    conn.execute(
+        "INSERT INTO videos (
+            video_seq, channel_code, title, video_type, play_count, like_count,
+            thumbnail, play_time, approx_published_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+        ON CONFLICT (video_seq) DO UPDATE SET
+            channel_code = excluded.channel_code,
+            title = excluded.title,
+            video_type = excluded.video_type,
+            play_count = COALESCE(excluded.play_count, videos.play_count),
+            like_count = COALESCE(excluded.like_count, videos.like_count),
+            thumbnail = COALESCE(excluded.thumbnail, videos.thumbnail),
+            play_time = COALESCE(excluded.play_time, videos.play_time),
+            approx_published_at = COALESCE(excluded.approx_published_at, videos.approx_published_at),
+            updated_at = excluded.updated_at",
+        params![
+            video.video_seq as i64,
+            video.channel_code,
+            video.title,
+            video.kind.as_str(),
+            video.plays.map(|p| p as i64),
+            video.likes.map(|l| l as i64),
+            video.thumbnail_url,
+            video.duration_secs.map(|d| d as i64),
+            video.published_at.map(|t| t.timestamp_millis()),
+            now_millis(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn upsert_board(conn: &Connection, board: &Board) -> Result<()> {
+    conn.execute(
+        "INSERT INTO boards (
+            board_id, channel_code, title, board_type, open_type, pay_required,
+            last_updated_at, data, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+        ON CONFLICT (board_id) DO UPDATE SET
+            channel_code = excluded.channel_code,
+            title = excluded.title,
+            board_type = excluded.board_type,
+            open_type = excluded.open_type,
+            pay_required = excluded.pay_required,
+            last_updated_at = excluded.last_updated_at,
+            data = excluded.data,
+            updated_at = excluded.updated_at",
+        params![
+            board.board_id,
+            board.channel_code,
+            board.title,
+            serde_json::to_value(board.board_type)?.as_str(),
+            board.open_type.as_str(),
+            board.pay_required,
+            board.last_updated_at.timestamp_millis(),
+            serde_json::to_string(board)?,
+            now_millis(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn upsert_post(conn: &Connection, post: &Post) -> Result<()> {
+    conn.execute(
+        "INSERT INTO posts (
+            post_id, board_id, channel_code, title, author_member_id,
+            author_nickname, content_type, plain_body, comment_count,
+            emotion_count, video_seq, created_at, data, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+        ON CONFLICT (post_id) DO UPDATE SET
+            board_id = excluded.board_id,
+            channel_code = excluded.channel_code,
+            title = excluded.title,
+            author_member_id = excluded.author_member_id,
+            author_nickname = excluded.author_nickname,
+            content_type = excluded.content_type,
+            plain_body = excluded.plain_body,
+            comment_count = excluded.comment_count,
+            emotion_count = excluded.emotion_count,
+            video_seq = excluded.video_seq,
+            created_at = excluded.created_at,
+            data = excluded.data,
+            updated_at = excluded.updated_at",
+        params![
+            post.post_id,
+            post.board.board_id,
+            post.channel.channel_code,
+            post.title,
+            post.author.member_id,
+            post.author.nickname,
+            post.content_type,
+            post.plain_body,
+            post.comment_count,
+            post.emotion_count,
+            post.official_video.as_ref().map(|v| v.video_seq),
+            post.created_at.timestamp_millis(),
+            serde_json::to_string(post)?,
+            now_millis(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Upserts every post in a page of board posts, in a single transaction
+pub fn upsert_board_posts(conn: &Connection, posts: &BoardPosts) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    for post in &posts.data {
+        upsert_post(&tx, post)?;
+    }
+
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Gets a channel's videos that went on air in `[from, to)`, oldest first
+pub fn get_channel_videos_between(
+    conn: &Connection,
+    channel_code: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<StoredVideo>> {
+    let mut stmt = conn.prepare(
+        "SELECT video_seq, channel_code, title, video_type, play_count, like_count,
+            comment_count, on_air_start_at, approx_published_at
+        FROM videos
+        WHERE channel_code = ?1 AND on_air_start_at >= ?2 AND on_air_start_at < ?3
+        ORDER BY on_air_start_at",
+    )?;
+
+    let videos = stmt
+        .query_map(
+            params![channel_code, from.timestamp_millis(), to.timestamp_millis()],
+            stored_video_from_row,
+        )?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(videos)
+}
+
+/// Gets a single video by seq, None if it hasn't been stored
+pub fn get_video(conn: &Connection, video_seq: u64) -> Result<Option<StoredVideo>> {
+    let video = conn
+        .query_row(
+            "SELECT video_seq, channel_code, title, video_type, play_count, like_count,
+                comment_count, on_air_start_at, approx_published_at
+            FROM videos
+            WHERE video_seq = ?1",
+            params![video_seq as i64],
+            stored_video_from_row,
+        )
+        .optional()?;
+
+    Ok(video)
+}
+
+fn stored_video_from_row(row: &rusqlite::Row) -> rusqlite::Result<StoredVideo> {
+    let video_seq: i64 = row.get(0)?;
+    let on_air_start_at: Option<i64> = row.get(7)?;
+    let approx_published_at: Option<i64> = row.get(8)?;
+
+    Ok(StoredVideo {
+        video_seq: video_seq as u64,
+        channel_code: row.get(1)?,
+        title: row.get(2)?,
+        video_type: row.get(3)?,
+        play_count: row.get(4)?,
+        like_count: row.get(5)?,
+        comment_count: row.get(6)?,
+        on_air_start_at: on_air_start_at.map(|t| Utc.timestamp_millis(t)),
+        approx_published_at: approx_published_at.map(|t| Utc.timestamp_millis(t)),
+    })
+}
+
+fn now_millis() -> i64 {
+    Utc::now().timestamp_millis()
+}
@@ -0,0 +1,77 @@
+use reqwest::{Client, Response};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::error::{Error, Result};
+
+/// Downloads `url` into `path`, overwriting any existing file.
+///
+/// The body is streamed to `<path>.part` and renamed once complete, so `path`
+/// only exists if the download finished. Callers that skip existing files
+/// won't skip a download that was cut off.
+pub(crate) async fn download_file(client: &Client, url: &str, path: &Path) -> Result<()> {
+    let mut response = client.get(url).send().await?.error_for_status()?;
+    let part_path = part_path(path);
+    let mut file = fs::File::create(&part_path).await?;
+
+    if let Err(e) = write_body(&mut response, &mut file, url).await {
+        drop(file);
+        let _ = fs::remove_file(&part_path).await;
+        return Err(e);
+    }
+
+    drop(file);
+    fs::rename(&part_path, path).await?;
+
+    Ok(())
+}
+
+/// Streams the response body into `file`, checking it against the
+/// `Content-Length` as it goes
+async fn write_body(response: &mut Response, file: &mut fs::File, url: &str) -> Result<()> {
+    let expected_len = response.content_length();
+    let mut len = 0u64;
+
+    while let Some(chunk) = response.chunk().await? {
+        len += chunk.len() as u64;
+
+        if let Some(expected_len) = expected_len {
+            if len > expected_len {
+                return Err(Error::Vlive(format!(
+                    "Download of {} is longer than the expected {} bytes",
+                    url, expected_len
+                )));
+            }
+        }
+
+        file.write_all(&chunk).await?;
+    }
+
+    // tokio files write in the background, flush before the file is renamed
+    file.flush().await?;
+
+    if let Some(expected_len) = expected_len {
+        if len != expected_len {
+            return Err(Error::Vlive(format!(
+                "Incomplete download of {}, got {} of {} bytes",
+                url, len, expected_len
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if a file exists without blocking
+pub(crate) async fn file_exists(path: &Path) -> bool {
+    fs::metadata(path).await.is_ok()
+}
+
+fn part_path(path: &Path) -> PathBuf {
+    let mut part = OsString::from(path.as_os_str());
+    part.push(".part");
+
+    PathBuf::from(part)
+}
@@ -23,6 +23,58 @@ pub fn board_posts_url(board_id: u64) -> String {
     )
 }
 
+pub fn search_videos_url(channel_code: &str) -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/search/v1.0/channel-{}/videos?appId={}&fields=attachments,author,availableActions,boardId,channel%7BchannelName,channelCode%7D,channelCode,commentCount,contentType,createdAt,emotionCount,isCommentEnabled,isHiddenFromStar,officialVideo,postId,postVersion,title,url&gcc=US&locale=en_US",
+        channel_code, APP_ID
+    )
+}
+
+pub fn search_posts_url(channel_code: &str) -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/search/v1.0/channel-{}/posts?appId={}&fields=attachments,author,availableActions,board%7BboardId,title,boardType,payRequired,includedCountries,excludedCountries%7D,channel%7BchannelName,channelCode%7D,commentCount,contentType,createdAt,emotionCount,excludedCountries,includedCountries,isCommentEnabled,isHiddenFromStar,plainBody,postId,postVersion,thumbnail,title,url,writtenIn,sharedPosts&gcc=US&locale=en_US",
+        channel_code, APP_ID
+    )
+}
+
+pub fn post_url(post_id: &str) -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/post/v1.0/post-{}?appId={}&fields=attachments,author,availableActions,board%7BboardId,title,boardType,payRequired,includedCountries,excludedCountries%7D,body,channel%7BchannelName,channelCode%7D,commentCount,contentType,createdAt,emotionCount,excludedCountries,includedCountries,isCommentEnabled,isHiddenFromStar,lastModifierMember,notice,officialVideo,plainBody,postId,postVersion,reservation,starReactions,targetMember,thumbnail,title,url,viewerEmotionId,writtenIn,sharedPosts,originPost&gcc=US&locale=en_US",
+        post_id, APP_ID
+    )
+}
+
+const COMMENT_FIELDS: &str =
+    "author,body,commentCount,commentId,createdAt,emotionCount,parent,root,writtenIn";
+
+pub fn comments_url(post_id: &str) -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/comment/v1.0/post-{}/comments?appId={}&fields={}&sort=LATEST&gcc=US&locale=en_US",
+        post_id, APP_ID, COMMENT_FIELDS
+    )
+}
+
+pub fn star_comments_url(post_id: &str) -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/comment/v1.0/post-{}/starComments?appId={}&fields={}&sort=LATEST&gcc=US&locale=en_US",
+        post_id, APP_ID, COMMENT_FIELDS
+    )
+}
+
+pub fn comment_replies_url(comment_id: &str) -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/comment/v1.0/comment-{}/comments?appId={}&fields={}&sort=LATEST&gcc=US&locale=en_US",
+        comment_id, APP_ID, COMMENT_FIELDS
+    )
+}
+
+pub fn current_member_url() -> String {
+    format!(
+        "https://www.vlive.tv/globalv-web/vam-web/member/v1.0/me?appId={}&fields=memberId,nickname,profileImageUrl,countryCode&gcc=US&locale=en_US",
+        APP_ID
+    )
+}
+
 pub fn channel_url(channel_code: &str) -> String {
     format!("https://www.vlive.tv/channel/{}", channel_code)
 }
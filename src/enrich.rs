@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use std::convert::TryFrom;
+
+use crate::error::{Error, Result};
+use crate::model::{recent_video::RecentVideo, video::OfficialVideo};
+use crate::VLiveRequester;
+
+/// A recent video merged with its full details from `get_video`
+#[derive(Debug, Clone)]
+pub struct EnrichedRecentVideo {
+    pub recent: RecentVideo,
+    pub video: Box<OfficialVideo>,
+}
+
+impl EnrichedRecentVideo {
+    /// Number of plays, preferring the count from the full video details
+    pub fn plays(&self) -> u64 {
+        u64::try_from(self.video.play_count)
+            .ok()
+            .or(self.recent.plays)
+            .unwrap_or_default()
+    }
+
+    /// Number of likes, preferring the count from the full video details
+    pub fn likes(&self) -> u64 {
+        u64::try_from(self.video.like_count)
+            .ok()
+            .or(self.recent.likes)
+            .unwrap_or_default()
+    }
+
+    pub fn comment_count(&self) -> i64 {
+        self.video.comment_count
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.video.created_at
+    }
+
+    pub fn on_air_start_at(&self) -> DateTime<Utc> {
+        self.video.on_air_start_at
+    }
+}
+
+/// Fetches the full details of a recent video
+pub async fn enrich<R>(requester: &R, recent: RecentVideo) -> Result<EnrichedRecentVideo>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let state = requester.get_video(recent.video_seq).await?;
+    let detail = state
+        .post_detail
+        .get_detail()
+        .ok_or(Error::MissingDetails)?;
+
+    Ok(EnrichedRecentVideo {
+        video: detail.official_video.clone(),
+        recent,
+    })
+}
+
+/// Fetches the full details of a page of recent videos, with at most
+/// `concurrency` requests at a time. Results are in the same order as the
+/// given videos.
+pub async fn enrich_all<R>(
+    requester: &R,
+    videos: Vec<RecentVideo>,
+    concurrency: usize,
+) -> Vec<Result<EnrichedRecentVideo>>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    stream::iter(videos)
+        .map(|video| enrich(requester, video))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
@@ -5,13 +5,19 @@ use std::io::Error as IoError;
 use std::result::Result as StdResult;
 
 use reqwest::Error as ReqwestError;
+#[cfg(feature = "sqlite")]
+use rusqlite::Error as SqliteError;
 
 /// Common result type used throughout the library.
 pub type Result<T> = StdResult<T, Error>;
 
 /// Common error type used throughout the library, to be used as a holder for
 /// errors from various other libraries.
+///
+/// Variants depend on enabled features, such as `Sqlite`, so matches on this
+/// need a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     IsLive,
     MissingDetails,
@@ -21,6 +27,9 @@ pub enum Error {
     Json(JsonError),
     /// A `std::io` module error.
     Io(IoError),
+    /// A `rusqlite` crate error.
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteError),
     /// A `vlive` crate error.
     Vlive(String),
 }
@@ -43,6 +52,13 @@ impl From<ReqwestError> for Error {
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<SqliteError> for Error {
+    fn from(err: SqliteError) -> Error {
+        Error::Sqlite(err)
+    }
+}
+
 impl<'a> From<&'a str> for Error {
     fn from(err: &'a str) -> Error {
         Error::Vlive(err.to_string())
@@ -57,6 +73,8 @@ impl Display for Error {
             Error::Reqwest(ref inner) => inner.fmt(f),
             Error::Json(ref inner) => inner.fmt(f),
             Error::Io(ref inner) => inner.fmt(f),
+            #[cfg(feature = "sqlite")]
+            Error::Sqlite(ref inner) => inner.fmt(f),
             Error::Vlive(ref inner) => inner.fmt(f),
         }
     }
@@ -68,6 +86,8 @@ impl StdError for Error {
             Error::Reqwest(ref inner) => Some(inner),
             Error::Json(ref inner) => Some(inner),
             Error::Io(ref inner) => Some(inner),
+            #[cfg(feature = "sqlite")]
+            Error::Sqlite(ref inner) => Some(inner),
             Error::IsLive | Error::MissingDetails | Error::Vlive(_) => None,
         }
     }
@@ -11,19 +11,44 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use reqwest::Client;
 
+pub mod archive;
+mod channel_id;
+#[cfg(feature = "sqlite")]
+pub mod db;
+mod download;
 mod endpoints;
+pub mod enrich;
 pub mod error;
 pub mod model;
-
+pub mod photos;
+pub mod products;
+pub mod query;
+pub mod raw;
+pub mod repost;
+pub mod session;
+pub mod star;
+pub mod stats;
+pub mod sync;
+pub mod time;
+pub mod tracker;
+pub mod url;
+
+pub use channel_id::ChannelId;
 pub use error::Error;
+pub use raw::RawResponse;
+pub use session::Session;
+pub use url::VLiveUrl;
 
 use endpoints::APP_ID;
 use error::Result;
 use model::{
-    board_posts::BoardPosts,
+    board_posts::{BoardPosts, Post},
     channel,
+    comment::Comments,
     grouped_board::{Board, GroupedBoards},
+    member::Member,
     recent_video::RecentVideo,
+    search::VideoSearchResults,
     video,
 };
 
@@ -38,90 +63,369 @@ macro_rules! api {
 
 #[async_trait]
 pub trait VLiveRequester {
-    async fn search_channel(&self, query: &str, num_rows: u64) -> Result<channel::ChannelList>;
-    async fn get_channel_info(&self, channel_code: &str) -> Result<channel::Channel>;
+    /// Search for a channel by name
+    async fn search_channel(&self, query: &str, num_rows: u64) -> Result<channel::ChannelList> {
+        self.search_channel_raw(query, num_rows).await?.into_value()
+    }
 
-    async fn decode_channel_code(&self, channel_code: &str) -> Result<u64>;
-    async fn get_channel_grouped_boards(&self, channel_code: &str) -> Result<GroupedBoards>;
-    async fn get_channel_board(&self, channel_code: &str, board_id: u64) -> Result<Board>;
-    async fn get_board_posts(&self, channel_code: &str, board_id: u64) -> Result<BoardPosts>;
+    /// Get basic information about a channel
+    async fn get_channel_info(&self, channel: &ChannelId) -> Result<channel::Channel> {
+        self.get_channel_info_raw(channel).await?.into_value()
+    }
+
+    async fn decode_channel_code(&self, channel_code: &str) -> Result<u32> {
+        self.decode_channel_code_raw(channel_code)
+            .await?
+            .into_value()
+    }
+
+    /// Get a channel's boards, grouped into different categories
+    async fn get_channel_grouped_boards(&self, channel: &ChannelId) -> Result<GroupedBoards> {
+        self.get_channel_grouped_boards_raw(channel)
+            .await?
+            .into_value()
+    }
+
+    /// Gets a channel's board info. Note that this doesn't include the actual board posts
+    /// Channel code is required since the referer requires the channel board URL
+    async fn get_channel_board(&self, channel: &ChannelId, board_id: u64) -> Result<Board> {
+        self.get_channel_board_raw(channel, board_id)
+            .await?
+            .into_value()
+    }
+
+    /// Get the posts in a given board
+    async fn get_board_posts(&self, channel: &ChannelId, board_id: u64) -> Result<BoardPosts> {
+        self.get_board_posts_raw(channel, board_id)
+            .await?
+            .into_value()
+    }
+
+    /// Get a page of posts in a given board. `after` is the cursor from
+    /// `BoardPosts::next_after` of the previous page, or None for the latest
+    /// posts
+    async fn get_board_posts_page(
+        &self,
+        channel: &ChannelId,
+        board_id: u64,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<BoardPosts> {
+        self.get_board_posts_page_raw(channel, board_id, limit, after)
+            .await?
+            .into_value()
+    }
+
+    /// Get a single post by ID, such as `0-12345678`. This includes the full
+    /// post body and works for both video and text/photo posts
+    async fn get_post(&self, post_id: &str) -> Result<Post> {
+        self.get_post_raw(post_id).await?.into_value()
+    }
 
     async fn get_channel_video_list(
         &self,
-        channel_seq: u32,
+        channel: &ChannelId,
         max_rows: u32,
         page_no: u32,
-    ) -> Result<channel::ChannelVideoList>;
+    ) -> Result<channel::ChannelVideoList> {
+        self.get_channel_video_list_raw(channel, max_rows, page_no)
+            .await?
+            .into_value()
+    }
 
     async fn get_upcoming_video_list(
         &self,
-        channel_seq: u32,
+        channel: &ChannelId,
         max_rows: u32,
         page_no: u32,
-    ) -> Result<channel::ChannelUpcomingVideoList>;
+    ) -> Result<channel::ChannelUpcomingVideoList> {
+        self.get_upcoming_video_list_raw(channel, max_rows, page_no)
+            .await?
+            .into_value()
+    }
+
+    /// Get the logged in member. This requires a client with session cookies,
+    /// such as from `Session::client`
+    async fn get_current_member(&self) -> Result<Member> {
+        self.get_current_member_raw().await?.into_value()
+    }
+
+    /// Fetches new videos from any channel (equivalent to the new section on the homepage)
+    async fn get_recent_videos(&self, page_size: u64, page_no: u64) -> Result<Vec<RecentVideo>> {
+        self.get_recent_videos_raw(page_size, page_no)
+            .await?
+            .into_value()
+    }
+
+    async fn get_video(&self, video_seq: u64) -> Result<video::VideoState> {
+        self.get_video_raw(video_seq).await?.into_value()
+    }
 
-    async fn get_recent_videos(&self, page_size: u64, page_no: u64) -> Result<Vec<RecentVideo>>;
-    async fn get_video(&self, video_seq: u64) -> Result<video::VideoState>;
-    async fn get_video_streams(&self, video_seq: u64) -> Result<video::Video>;
+    /// Get detailed information about a given video
+    async fn get_video_streams(&self, video_seq: u64) -> Result<video::Video> {
+        self.get_video_streams_raw(video_seq).await?.into_value()
+    }
+
+    /// Search a channel's videos by title. Pass the cursor from
+    /// `VideoSearchResults::next_after` as `after` to get the next page
+    async fn search_channel_videos(
+        &self,
+        channel: &ChannelId,
+        query: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<VideoSearchResults> {
+        self.search_channel_videos_raw(channel, query, limit, after)
+            .await?
+            .into_value()
+    }
+
+    /// Search a channel's posts. Pass the cursor from `BoardPosts::next_after`
+    /// as `after` to get the next page
+    async fn search_channel_posts(
+        &self,
+        channel: &ChannelId,
+        query: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<BoardPosts> {
+        self.search_channel_posts_raw(channel, query, limit, after)
+            .await?
+            .into_value()
+    }
+
+    /// Get comments on a post, newest first. Video comments are on the
+    /// video's post, see `PostDetail::post_id`
+    async fn get_comments(
+        &self,
+        post_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<Comments> {
+        self.get_comments_raw(post_id, limit, after)
+            .await?
+            .into_value()
+    }
+
+    /// Get only comments written by stars (artists) on a post
+    async fn get_star_comments(
+        &self,
+        post_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<Comments> {
+        self.get_star_comments_raw(post_id, limit, after)
+            .await?
+            .into_value()
+    }
+
+    /// Get replies to a comment on a post. The post ID of a comment is in
+    /// `Comment::post_id`
+    async fn get_comment_replies(
+        &self,
+        post_id: &str,
+        comment_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<Comments> {
+        self.get_comment_replies_raw(post_id, comment_id, limit, after)
+            .await?
+            .into_value()
+    }
+
+    // Raw counterparts, these return the response body, status and headers
+    // along with the parsed value. Implementors only need to provide these,
+    // the methods above parse the value out of them by default
+
+    /// [`search_channel`](Self::search_channel) with the raw response
+    async fn search_channel_raw(
+        &self,
+        query: &str,
+        num_rows: u64,
+    ) -> Result<RawResponse<channel::ChannelList>>;
+    /// [`get_channel_info`](Self::get_channel_info) with the raw response
+    async fn get_channel_info_raw(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<RawResponse<channel::Channel>>;
+
+    /// [`decode_channel_code`](Self::decode_channel_code) with the raw response
+    async fn decode_channel_code_raw(&self, channel_code: &str) -> Result<RawResponse<u32>>;
+    /// [`get_channel_grouped_boards`](Self::get_channel_grouped_boards) with the raw response
+    async fn get_channel_grouped_boards_raw(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<RawResponse<GroupedBoards>>;
+    /// [`get_channel_board`](Self::get_channel_board) with the raw response
+    async fn get_channel_board_raw(
+        &self,
+        channel: &ChannelId,
+        board_id: u64,
+    ) -> Result<RawResponse<Board>>;
+    /// [`get_board_posts`](Self::get_board_posts) with the raw response
+    async fn get_board_posts_raw(
+        &self,
+        channel: &ChannelId,
+        board_id: u64,
+    ) -> Result<RawResponse<BoardPosts>>;
+    /// [`get_board_posts_page`](Self::get_board_posts_page) with the raw response
+    async fn get_board_posts_page_raw(
+        &self,
+        channel: &ChannelId,
+        board_id: u64,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<BoardPosts>>;
+    /// [`get_post`](Self::get_post) with the raw response
+    async fn get_post_raw(&self, post_id: &str) -> Result<RawResponse<Post>>;
+
+    /// [`get_channel_video_list`](Self::get_channel_video_list) with the raw response
+    async fn get_channel_video_list_raw(
+        &self,
+        channel: &ChannelId,
+        max_rows: u32,
+        page_no: u32,
+    ) -> Result<RawResponse<channel::ChannelVideoList>>;
+
+    /// [`get_upcoming_video_list`](Self::get_upcoming_video_list) with the raw response
+    async fn get_upcoming_video_list_raw(
+        &self,
+        channel: &ChannelId,
+        max_rows: u32,
+        page_no: u32,
+    ) -> Result<RawResponse<channel::ChannelUpcomingVideoList>>;
+
+    /// [`get_current_member`](Self::get_current_member) with the raw response
+    async fn get_current_member_raw(&self) -> Result<RawResponse<Member>>;
+
+    /// [`get_recent_videos`](Self::get_recent_videos) with the raw response
+    async fn get_recent_videos_raw(
+        &self,
+        page_size: u64,
+        page_no: u64,
+    ) -> Result<RawResponse<Vec<RecentVideo>>>;
+    /// [`get_video`](Self::get_video) with the raw response
+    async fn get_video_raw(&self, video_seq: u64) -> Result<RawResponse<video::VideoState>>;
+    /// [`get_video_streams`](Self::get_video_streams) with the raw response
+    async fn get_video_streams_raw(&self, video_seq: u64) -> Result<RawResponse<video::Video>>;
+
+    /// [`search_channel_videos`](Self::search_channel_videos) with the raw response
+    async fn search_channel_videos_raw(
+        &self,
+        channel: &ChannelId,
+        query: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<VideoSearchResults>>;
+
+    /// [`search_channel_posts`](Self::search_channel_posts) with the raw response
+    async fn search_channel_posts_raw(
+        &self,
+        channel: &ChannelId,
+        query: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<BoardPosts>>;
+
+    /// [`get_comments`](Self::get_comments) with the raw response
+    async fn get_comments_raw(
+        &self,
+        post_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<Comments>>;
+
+    /// [`get_star_comments`](Self::get_star_comments) with the raw response
+    async fn get_star_comments_raw(
+        &self,
+        post_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<Comments>>;
+
+    /// [`get_comment_replies`](Self::get_comment_replies) with the raw response
+    async fn get_comment_replies_raw(
+        &self,
+        post_id: &str,
+        comment_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<Comments>>;
 }
 
 #[async_trait]
 impl VLiveRequester for Client {
-    /// Search for a channel by name
     #[tracing::instrument]
-    async fn search_channel(&self, query: &str, num_rows: u64) -> Result<channel::ChannelList> {
-        self.get("http://www.vlive.tv/search/auto/channels")
+    async fn search_channel_raw(
+        &self,
+        query: &str,
+        num_rows: u64,
+    ) -> Result<RawResponse<channel::ChannelList>> {
+        let response = self
+            .get("http://www.vlive.tv/search/auto/channels")
             .query(&[("query", query), ("maxNumOfRows", &num_rows.to_string())])
             .send()
-            .await?
-            .json::<channel::ChannelList>()
-            .await
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
     }
 
-    /// Get basic information about a channel
     #[tracing::instrument]
-    async fn get_channel_info(&self, channel_code: &str) -> Result<channel::Channel> {
+    async fn get_channel_info_raw(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<RawResponse<channel::Channel>> {
+        let channel_code = channel.code(self).await?;
         let channel_url = endpoints::channel_url(channel_code);
-        let response = self.get(&channel_url).send().await?.text().await?;
+        let response = self.get(&channel_url).send().await?;
 
-        let s = find_inline_state(&response)?;
-
-        Ok(s.channel.channel)
+        RawResponse::from_response(response, |body| {
+            find_inline_state(body).map(|s| s.channel.channel)
+        })
+        .await
     }
 
     #[tracing::instrument]
-    async fn decode_channel_code(&self, channel_code: &str) -> Result<u64> {
-        self.get(api!("decodeChannelCode"))
-            .query(&[("app_id", APP_ID), ("channelCode", &channel_code)])
+    async fn decode_channel_code_raw(&self, channel_code: &str) -> Result<RawResponse<u32>> {
+        let response = self
+            .get(api!("decodeChannelCode"))
+            .query(&[("app_id", APP_ID), ("channelCode", channel_code)])
             .send()
-            .await?
-            .json::<channel::DecodeChannelCodeResult>()
-            .await
-            .map(|d| d.result.channel_seq)
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, |body| {
+            raw::json::<channel::DecodeChannelCodeResult>(body).map(|d| d.result.channel_seq)
+        })
+        .await
     }
 
-    /// Get a channel's boards, grouped into different categories
     #[tracing::instrument]
-    async fn get_channel_grouped_boards(&self, channel_code: &str) -> Result<GroupedBoards> {
-        self.get(&endpoints::grouped_boards_url(&channel_code))
+    async fn get_channel_grouped_boards_raw(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<RawResponse<GroupedBoards>> {
+        let channel_code = channel.code(self).await?;
+        let response = self
+            .get(endpoints::grouped_boards_url(channel_code))
             .header(
                 reqwest::header::REFERER,
                 (format!("https://www.vlive.tv/channel/{}", channel_code)),
             )
             .send()
-            .await?
-            .json::<GroupedBoards>()
-            .await
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
     }
 
-    /// Gets a channel's board info. Note that this doesn't include the actual board posts
-    /// Channel code is required since the referer requires the channel board URL
     #[tracing::instrument]
-    async fn get_channel_board(&self, channel_code: &str, board_id: u64) -> Result<Board> {
-        self.get(&endpoints::board_url(board_id))
+    async fn get_channel_board_raw(
+        &self,
+        channel: &ChannelId,
+        board_id: u64,
+    ) -> Result<RawResponse<Board>> {
+        let channel_code = channel.code(self).await?;
+        let response = self
+            .get(endpoints::board_url(board_id))
             .header(
                 reqwest::header::REFERER,
                 (format!(
@@ -130,16 +434,20 @@ impl VLiveRequester for Client {
                 )),
             )
             .send()
-            .await?
-            .json::<Board>()
-            .await
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
     }
 
-    /// Get the posts in a given board
     #[tracing::instrument]
-    async fn get_board_posts(&self, channel_code: &str, board_id: u64) -> Result<BoardPosts> {
-        self.get(&endpoints::board_posts_url(board_id))
+    async fn get_board_posts_raw(
+        &self,
+        channel: &ChannelId,
+        board_id: u64,
+    ) -> Result<RawResponse<BoardPosts>> {
+        let channel_code = channel.code(self).await?;
+        let response = self
+            .get(endpoints::board_posts_url(board_id))
             .header(
                 reqwest::header::REFERER,
                 (format!(
@@ -148,20 +456,60 @@ impl VLiveRequester for Client {
                 )),
             )
             .send()
-            .await?
-            .json::<BoardPosts>()
-            .await
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
     }
 
     #[tracing::instrument]
-    async fn get_channel_video_list(
+    async fn get_board_posts_page_raw(
         &self,
-        channel_seq: u32,
+        channel: &ChannelId,
+        board_id: u64,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<BoardPosts>> {
+        let channel_code = channel.code(self).await?;
+        let response = self
+            .get(endpoints::board_posts_url(board_id))
+            .query(&paging_query(limit, after))
+            .header(
+                reqwest::header::REFERER,
+                (format!(
+                    "https://www.vlive.tv/channel/{}/board/{}",
+                    channel_code, board_id
+                )),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+
+    #[tracing::instrument]
+    async fn get_post_raw(&self, post_id: &str) -> Result<RawResponse<Post>> {
+        let response = self
+            .get(endpoints::post_url(post_id))
+            .header(
+                reqwest::header::REFERER,
+                (format!("https://www.vlive.tv/post/{}", post_id)),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+
+    #[tracing::instrument]
+    async fn get_channel_video_list_raw(
+        &self,
+        channel: &ChannelId,
         max_rows: u32,
         page_no: u32,
-    ) -> Result<channel::ChannelVideoList> {
-        self.get(api!("getChannelVideoList"))
+    ) -> Result<RawResponse<channel::ChannelVideoList>> {
+        let channel_seq = channel.seq(self).await?;
+        let response = self
+            .get(api!("getChannelVideoList"))
             .query(&[
                 ("app_id", APP_ID),
                 ("channelSeq", &channel_seq.to_string()),
@@ -169,21 +517,24 @@ impl VLiveRequester for Client {
                 ("pageNo", &page_no.to_string()),
             ])
             .send()
-            .await?
-            .json::<channel::ChannelVideoListResult>()
-            .await
-            .map(|r| r.result)
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, |body| {
+            raw::json::<channel::ChannelVideoListResult>(body).map(|r| r.result)
+        })
+        .await
     }
 
     #[tracing::instrument]
-    async fn get_upcoming_video_list(
+    async fn get_upcoming_video_list_raw(
         &self,
-        channel_seq: u32,
+        channel: &ChannelId,
         max_rows: u32,
         page_no: u32,
-    ) -> Result<channel::ChannelUpcomingVideoList> {
-        self.get(api!("getUpcomingVideoList"))
+    ) -> Result<RawResponse<channel::ChannelUpcomingVideoList>> {
+        let channel_seq = channel.seq(self).await?;
+        let response = self
+            .get(api!("getUpcomingVideoList"))
             .query(&[
                 ("app_id", APP_ID),
                 ("channelSeq", &channel_seq.to_string()),
@@ -191,40 +542,64 @@ impl VLiveRequester for Client {
                 ("pageNo", &page_no.to_string()),
             ])
             .send()
-            .await?
-            .json::<channel::ChannelUpcomingVideoListResult>()
-            .await
-            .map(|r| r.result)
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, |body| {
+            raw::json::<channel::ChannelUpcomingVideoListResult>(body).map(|r| r.result)
+        })
+        .await
     }
 
-    /// Fetches new videos from any channel (equivalent to the new section on the homepage)
     #[tracing::instrument]
-    async fn get_recent_videos(&self, page_size: u64, page_no: u64) -> Result<Vec<RecentVideo>> {
-        self.get("https://www.vlive.tv/home/video/more")
+    async fn get_current_member_raw(&self) -> Result<RawResponse<Member>> {
+        let response = self.get(endpoints::current_member_url()).send().await?;
+        let logged_in = !matches!(
+            response.status(),
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN
+        );
+
+        RawResponse::from_response(response, |body| {
+            if logged_in {
+                raw::json(body)
+            } else {
+                Err(Error::from(
+                    "Not logged in, session cookies are missing or expired",
+                ))
+            }
+        })
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn get_recent_videos_raw(
+        &self,
+        page_size: u64,
+        page_no: u64,
+    ) -> Result<RawResponse<Vec<RecentVideo>>> {
+        let response = self
+            .get("https://www.vlive.tv/home/video/more")
             .query(&[
                 ("pageNo", &page_no.to_string()),
                 ("pageSize", &page_size.to_string()),
             ])
             .send()
-            .await?
-            .text()
-            .await
-            .map_err(From::from)
-            .and_then(|text| RecentVideo::from_html(&text))
+            .await?;
+
+        RawResponse::from_response(response, RecentVideo::from_html).await
     }
 
     #[tracing::instrument]
-    async fn get_video(&self, video_seq: u64) -> Result<video::VideoState> {
+    async fn get_video_raw(&self, video_seq: u64) -> Result<RawResponse<video::VideoState>> {
         let video_url = endpoints::video_url(video_seq);
-        let response = self.get(&video_url).send().await?.text().await?;
+        let response = self.get(&video_url).send().await?;
 
-        find_inline_state(&response)
+        RawResponse::from_response(response, find_inline_state).await
     }
 
-    /// Get detailed information about a given video
+    /// Raw response of the final VOD request, earlier requests for the video
+    /// page and key are parsed as usual
     #[tracing::instrument]
-    async fn get_video_streams(&self, video_seq: u64) -> Result<video::Video> {
+    async fn get_video_streams_raw(&self, video_seq: u64) -> Result<RawResponse<video::Video>> {
         let video_url = endpoints::video_url(video_seq);
         tracing::debug!("video_url: {}", video_url);
         let video_state = self.get_video(video_seq).await?;
@@ -243,7 +618,7 @@ impl VLiveRequester for Client {
         tracing::debug!("video_id: {}", video_id);
 
         let video_key = self
-            .get(&endpoints::inkey_url(video_seq))
+            .get(endpoints::inkey_url(video_seq))
             .header(reqwest::header::REFERER, video_url)
             // Requires user agent or error 500
             .header(reqwest::header::USER_AGENT, "vlive-rs")
@@ -254,13 +629,137 @@ impl VLiveRequester for Client {
 
         tracing::debug!("video_key: {:?}", video_key);
 
-        self.get(&endpoints::vod_url(&video_id, &video_key.inkey))
+        let response = self
+            .get(endpoints::vod_url(video_id, &video_key.inkey))
             .send()
-            .await?
-            .json::<video::Video>()
-            .await
-            .map_err(From::from)
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+
+    #[tracing::instrument]
+    async fn search_channel_videos_raw(
+        &self,
+        channel: &ChannelId,
+        query: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<VideoSearchResults>> {
+        let channel_code = channel.code(self).await?;
+        let response = self
+            .get(endpoints::search_videos_url(channel_code))
+            .query(&search_query(query, limit, after))
+            .header(
+                reqwest::header::REFERER,
+                (format!("https://www.vlive.tv/channel/{}", channel_code)),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+
+    #[tracing::instrument]
+    async fn search_channel_posts_raw(
+        &self,
+        channel: &ChannelId,
+        query: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<BoardPosts>> {
+        let channel_code = channel.code(self).await?;
+        let response = self
+            .get(endpoints::search_posts_url(channel_code))
+            .query(&search_query(query, limit, after))
+            .header(
+                reqwest::header::REFERER,
+                (format!("https://www.vlive.tv/channel/{}", channel_code)),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+
+    #[tracing::instrument]
+    async fn get_comments_raw(
+        &self,
+        post_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<Comments>> {
+        let response = self
+            .get(endpoints::comments_url(post_id))
+            .query(&paging_query(limit, after))
+            .header(
+                reqwest::header::REFERER,
+                (format!("https://www.vlive.tv/post/{}", post_id)),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
     }
+
+    #[tracing::instrument]
+    async fn get_star_comments_raw(
+        &self,
+        post_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<Comments>> {
+        let response = self
+            .get(endpoints::star_comments_url(post_id))
+            .query(&paging_query(limit, after))
+            .header(
+                reqwest::header::REFERER,
+                (format!("https://www.vlive.tv/post/{}", post_id)),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+
+    #[tracing::instrument]
+    async fn get_comment_replies_raw(
+        &self,
+        post_id: &str,
+        comment_id: &str,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<Comments>> {
+        let response = self
+            .get(endpoints::comment_replies_url(comment_id))
+            .query(&paging_query(limit, after))
+            .header(
+                reqwest::header::REFERER,
+                format!("https://www.vlive.tv/post/{}", post_id),
+            )
+            .send()
+            .await?;
+
+        RawResponse::from_response(response, raw::json).await
+    }
+}
+
+/// Query params for search endpoints
+fn search_query(query: &str, limit: u32, after: Option<&str>) -> Vec<(&'static str, String)> {
+    let mut params = vec![("query", query.to_string())];
+    params.extend(paging_query(limit, after));
+
+    params
+}
+
+/// Query params for cursor paginated endpoints, `after` is only included if given
+fn paging_query(limit: u32, after: Option<&str>) -> Vec<(&'static str, String)> {
+    let mut params = vec![("limit", limit.to_string())];
+
+    if let Some(after) = after {
+        params.push(("after", after.to_string()));
+    }
+
+    params
 }
 
 fn find_inline_state(s: &str) -> Result<video::VideoState> {
@@ -280,7 +779,7 @@ fn find_inline_state(s: &str) -> Result<video::VideoState> {
     // let json_val: serde_json::Value = serde_json::from_str(json_str)?;
     // let json_str = serde_json::to_string_pretty(&json_val).unwrap();
     // println!("{}", &json_str);
-    let state: video::VideoState = serde_json::from_str(&json_str)?;
+    let state: video::VideoState = serde_json::from_str(json_str)?;
 
     Ok(state)
 }
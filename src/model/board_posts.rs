@@ -1,21 +1,48 @@
-use super::grouped_board::BoardType;
+use super::helpers::{option_lenient, vec_of_lenient};
+use super::video::OfficialVideo;
+use chrono::serde::{ts_milliseconds, ts_milliseconds_option};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-#[derive(Debug, Serialize, Deserialize)]
+pub use super::common::{Author, PartialBoard, PartialChannel, ProfileType};
+
+#[deprecated(note = "Use `common::PartialBoard`")]
+pub type Board = PartialBoard;
+
+#[deprecated(note = "Use `common::PartialChannel`")]
+pub type Channel = PartialChannel;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BoardPosts {
     pub paging: Paging,
     pub data: Vec<Post>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl BoardPosts {
+    /// Cursor for the next page of posts, None if this is the last page
+    pub fn next_after(&self) -> Option<&str> {
+        self.paging.next_params.as_ref().map(|p| p.after.as_str())
+    }
+
+    /// Removes reposts of a post that is already in this page, keeping the
+    /// first occurrence of each original post
+    pub fn dedup_reposts(&mut self) {
+        let mut seen = HashSet::new();
+        self.data
+            .retain(|p| seen.insert(p.original_post_id().to_string()));
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Post {
     pub attachments: Attachments,
     pub url: String,
     pub title: String,
-    pub created_at: i64,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
     pub available_actions: Vec<String>,
     pub comment_count: i64,
     pub written_in: String,
@@ -26,59 +53,181 @@ pub struct Post {
     pub post_version: String,
     pub thumbnail: Thumbnail,
     pub plain_body: String,
+    /// Full HTML body, only included when fetching a single post
+    pub body: Option<String>,
     pub content_type: String,
-    pub shared_posts: Vec<Option<serde_json::Value>>,
+    /// Posts shared in this post, None if the shared post was deleted or
+    /// couldn't be parsed
+    #[serde(default, deserialize_with = "vec_of_lenient")]
+    pub shared_posts: Vec<Option<SharedPost>>,
+    /// Original post if this is a repost, None if it couldn't be parsed
+    #[serde(default, deserialize_with = "option_lenient")]
+    pub origin_post: Option<Box<OriginPost>>,
     pub author: Author,
-    pub channel: Channel,
-    pub board: Board,
+    pub channel: PartialChannel,
+    pub board: PartialBoard,
+    /// Video in this post, None for text and photo posts
+    pub official_video: Option<Box<OfficialVideo>>,
+    /// Reactions from stars (artists) of the channel
+    #[serde(default)]
+    pub star_reactions: Vec<StarReaction>,
+    /// Emotion the current viewer reacted with, always None unless logged in
+    pub viewer_emotion_id: Option<String>,
+    /// Set if this post is scheduled to be published later
+    pub reservation: Option<Reservation>,
+    /// If this post is pinned as a notice
+    #[serde(default)]
+    pub notice: bool,
+    /// Member this post is directed at, such as posts on a star's fan board
+    pub target_member: Option<Author>,
+    /// Member who last edited this post
+    pub last_modifier_member: Option<Author>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Post {
+    /// Checks if this is a video post
+    pub fn is_video(&self) -> bool {
+        self.official_video.is_some()
+    }
+
+    /// Checks if this post is a repost of another post
+    pub fn is_repost(&self) -> bool {
+        self.origin_post.is_some()
+    }
+
+    /// ID of the original post if this is a repost, otherwise this post's ID
+    pub fn original_post_id(&self) -> &str {
+        self.origin_post
+            .as_ref()
+            .map(|p| p.original_post_id())
+            .unwrap_or(&self.post_id)
+    }
+
+    /// Checks if this post is scheduled and not yet published
+    pub fn is_scheduled(&self) -> bool {
+        self.reservation.is_some()
+    }
+}
+
+/// Partial post shared in or by another post
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedPost {
+    pub post_id: String,
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub plain_body: Option<String>,
+    pub content_type: Option<String>,
+    #[serde(default, with = "ts_milliseconds_option")]
+    pub created_at: Option<DateTime<Utc>>,
+    pub author: Option<Author>,
+    pub channel: Option<PartialChannel>,
+    /// Original post if this is also a repost
+    #[serde(default, deserialize_with = "option_lenient")]
+    pub origin_post: Option<Box<SharedPost>>,
+}
+
+/// Post that a repost was shared from
+pub type OriginPost = SharedPost;
+
+impl SharedPost {
+    /// ID of the first post in the share chain
+    pub fn original_post_id(&self) -> &str {
+        let mut post = self;
+
+        while let Some(origin) = &post.origin_post {
+            post = origin;
+        }
+
+        &post.post_id
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StarReaction {
+    /// Emotion reacted with, such as `like`
+    pub emotion_id: String,
+    /// Star who reacted
+    pub member: Author,
+    #[serde(default, with = "ts_milliseconds_option")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Reservation {
+    /// When this post will be published
+    #[serde(with = "ts_milliseconds")]
+    pub reserved_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Attachments {
     pub video_count: i64,
+    #[serde(default)]
     pub photo: HashMap<String, Photo>,
     pub photo_count: i64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Photo {
     pub url: String,
     pub width: i64,
-    pub created_at: i64,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
     pub height: i64,
     pub photo_id: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Author {
-    pub member_id: String,
-    pub channel_code: String,
-    pub joined: bool,
-    pub nickname: String,
-    pub profile_image_url: String,
-    pub official_profile_type: String,
-}
+impl Photo {
+    /// URL of the original resolution image, without the `?type=` resize param
+    pub fn original_url(&self) -> &str {
+        match self.url.find('?') {
+            Some(pos) => &self.url[..pos],
+            None => &self.url,
+        }
+    }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Board {
-    pub board_id: i64,
-    pub title: String,
-    pub board_type: BoardType,
-    pub pay_required: bool,
-}
+    /// Stable file name for this photo, `{created_at}_{photo_id}.{ext}`
+    pub fn file_name(&self) -> String {
+        let url = self.original_url();
+        let ext = url
+            .rsplit('/')
+            .next()
+            .and_then(|name| name.rsplit_once('.'))
+            .map(|(_, ext)| ext.to_lowercase())
+            .filter(|ext| !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or_else(|| "jpg".to_string());
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Channel {
-    pub channel_code: String,
-    pub channel_name: String,
+        let photo_id: String = self
+            .photo_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        format!(
+            "{}_{}.{}",
+            self.created_at.timestamp_millis(),
+            photo_id,
+            ext
+        )
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Thumbnail {
     #[serde(rename = "type")]
@@ -86,13 +235,15 @@ pub struct Thumbnail {
     pub url: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Paging {
-    pub next_params: NextParams,
+    /// Missing on the last page
+    #[serde(default)]
+    pub next_params: Option<NextParams>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct NextParams {
     pub limit: String,
@@ -1,7 +1,11 @@
+use super::video::{ExposeStatus, ProductType, ScreenOrientation, VideoType};
 use crate::model::helpers::*;
-use chrono::{offset::FixedOffset, DateTime};
+use chrono::serde::{ts_milliseconds, ts_milliseconds_option};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+pub use super::common::PartialChannel;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChannelWrapper {
@@ -22,7 +26,8 @@ pub struct Channel {
     pub channel_description: Option<String>,
     pub sns_share_img: Option<String>,
     pub qr_code: Option<String>,
-    pub open_at: Option<i64>,
+    #[serde(default, with = "ts_milliseconds_option")]
+    pub open_at: Option<DateTime<Utc>>,
     pub show_upcoming: Option<bool>,
     pub use_member_level: Option<bool>,
     pub member_count: Option<i64>,
@@ -31,6 +36,10 @@ pub struct Channel {
     pub video_play_count_of_star: Option<i64>,
     pub video_like_count_of_star: Option<i64>,
     pub video_comment_count_of_star: Option<i64>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
 }
 
 impl Channel {
@@ -39,13 +48,6 @@ impl Channel {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct PartialChannel {
-    pub channel_code: String,
-    pub channel_name: String,
-}
-
 /// Type of channel, basic or CHANNEL+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum ChannelType {
@@ -80,7 +82,8 @@ pub struct ChannelListItem {
 pub struct ChannelBasicInfo {
     pub profile_img: String,
     pub name: String,
-    pub latest_updated_at: i64,
+    #[serde(with = "ts_milliseconds")]
+    pub latest_updated_at: DateTime<Utc>,
     pub channel_code: String,
 }
 
@@ -125,10 +128,14 @@ pub struct ChannelInfo {
     /// If there are upcoming videos
     #[serde(deserialize_with = "bool_from_str")]
     pub upcoming_show_yn: bool, //  "N"
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
 }
 
 impl ChannelInfo {
-    /// Gets the URL to this video.
+    /// Gets the URL to this channel.
     pub fn url(&self) -> String {
         format!("http://channels.vlive.tv/{}", self.channel_code)
     }
@@ -140,7 +147,7 @@ pub struct VideoListItem {
     /// The ID of the video, used in the URL.
     pub video_seq: u32, //  57788
     /// Type of video, either VOD or LIVE
-    pub video_type: String, //  "VOD"
+    pub video_type: VideoType, //  "VOD"
     /// Title of the video.
     pub title: String, //  "[1년 전 오늘의 TWICE] 지효야 1년 …’s birthday a year ago)"
     /// Number of plays this video has.
@@ -153,20 +160,22 @@ pub struct VideoListItem {
     pub thumbnail: String, //  "http://v.phinf.naver.net…6_20.%BD%BA%C6%BF002.jpg"
     pub pick_sort_order: u32, //  0
     /// Screen orientation of this video, either `HORIZONTAL` or `VERTICAL`
-    pub screen_orientation: String, //  "HORIZONTAL"
+    pub screen_orientation: ScreenOrientation, //  "HORIZONTAL"
 
     /// Upload date of this video(?)  This video may not have been visible at this point.
     #[serde(deserialize_with = "timestamp_from_str")]
-    pub will_start_at: DateTime<FixedOffset>, //  "2018-02-01 20:39:00"
+    pub will_start_at: DateTime<Utc>, //  "2018-02-01 20:39:00"
 
     /// End time of this video, usually sometime in 2099 so this isn't really useful.
     #[serde(deserialize_with = "timestamp_from_str")]
-    pub will_end_at: DateTime<FixedOffset>, //  "2099-12-31 23:59:59"
+    pub will_end_at: DateTime<Utc>, //  "2099-12-31 23:59:59"
 
     #[serde(default)]
     #[serde(deserialize_with = "option_timestamp_from_str")]
-    pub created_at: Option<DateTime<FixedOffset>>, //  "2018-04-06 13:35:09"
-    pub upcoming_yn: String, //  "N"
+    pub created_at: Option<DateTime<Utc>>, //  "2018-04-06 13:35:09"
+    /// If this video hasn't started yet.
+    #[serde(deserialize_with = "bool_from_str")]
+    pub upcoming_yn: bool, //  "N"
 
     /// If this is a "special" live video.
     #[serde(deserialize_with = "bool_from_str")]
@@ -180,18 +189,22 @@ pub struct VideoListItem {
     /// VLIVE+ package ID if this is a VLIVE+ video, otherwise it's empty.
     pub package_product_id: String, //  ""
     /// If this is a VLIVE+ product video.
-    pub product_type: String, //  "NONE" / "PAID"
+    pub product_type: ProductType, //  "NONE" / "PAID"
     /// Duration of the video in seconds.
     pub play_time: u32, //  199
 
     /// If this is a basic or CHANNEL+ video
     #[serde(deserialize_with = "bool_from_str")]
     pub channel_plus_public_yn: bool, //  "N"
-    pub expose_status: String, //  "EXPOSED"
+    pub expose_status: ExposeStatus, //  "EXPOSED"
 
     /// Date when this video was available.
     #[serde(deserialize_with = "timestamp_from_str")]
-    pub on_air_start_at: DateTime<FixedOffset>, //  "2018-02-01 20:44:00"
+    pub on_air_start_at: DateTime<Utc>, //  "2018-02-01 20:44:00"
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
 }
 
 impl VideoListItem {
@@ -202,7 +215,17 @@ impl VideoListItem {
 
     /// Checks if this video is currently live.
     pub fn is_live(&self) -> bool {
-        self.video_type == "LIVE"
+        self.video_type == VideoType::LIVE
+    }
+
+    /// Checks if this is a paid VLIVE+ video.
+    pub fn is_paid(&self) -> bool {
+        self.product_type.is_paid()
+    }
+
+    /// Checks if this is a vertical video.
+    pub fn is_vertical(&self) -> bool {
+        self.screen_orientation.is_vertical()
     }
 }
 
@@ -244,7 +267,7 @@ pub(crate) struct ChannelVideoListResult {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DecodeChannelCode {
-    pub channel_seq: u64,
+    pub channel_seq: u32,
     pub channel_code: String,
 }
 
@@ -0,0 +1,76 @@
+use super::board_posts::Paging;
+use super::common::Author;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A page of comments on a post or replies to a comment
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Comments {
+    pub paging: Paging,
+    pub data: Vec<Comment>,
+}
+
+impl Comments {
+    /// Cursor for the next page of comments, None if this is the last page
+    pub fn next_after(&self) -> Option<&str> {
+        self.paging.next_params.as_ref().map(|p| p.after.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub comment_id: String,
+    pub body: String,
+    pub author: Author,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
+    /// Number of replies to this comment
+    #[serde(default)]
+    pub comment_count: i64,
+    #[serde(default)]
+    pub emotion_count: i64,
+    /// Language the comment was written in, such as `en` or `ko`
+    pub written_in: Option<String>,
+    /// Comment or post this is a reply to
+    pub parent: Option<CommentParent>,
+    /// Post this comment thread belongs to
+    pub root: Option<CommentParent>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
+}
+
+impl Comment {
+    /// Checks if this comment has any replies
+    pub fn has_replies(&self) -> bool {
+        self.comment_count > 0
+    }
+
+    /// ID of the post this comment is on
+    pub fn post_id(&self) -> Option<&str> {
+        self.root
+            .as_ref()
+            .filter(|r| r.kind == "POST")
+            .map(|r| r.id.as_str())
+    }
+
+    /// Checks if this comment is a reply to another comment
+    pub fn is_reply(&self) -> bool {
+        self.parent
+            .as_ref()
+            .map(|p| p.kind == "COMMENT")
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentParent {
+    /// Either `POST` or `COMMENT`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub id: String,
+}
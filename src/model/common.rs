@@ -0,0 +1,181 @@
+//! Models shared between videos, boards and posts
+
+use super::board_posts::{self, Attachments};
+use super::channel::{Channel, ChannelInfo};
+use super::grouped_board::{self, BoardType, OpenType};
+use super::helpers::string_enum;
+use super::video::{OfficialVideo, PostDetail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Author of a post or comment, or a member referenced by one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Author {
+    pub member_id: String,
+    pub channel_code: String,
+    pub joined: bool,
+    pub nickname: String,
+    pub profile_image_url: String,
+    pub official_profile_type: ProfileType,
+}
+
+impl Author {
+    /// Checks if this author is a star (artist) of the channel
+    pub fn is_star(&self) -> bool {
+        self.official_profile_type == ProfileType::Star
+    }
+}
+
+string_enum! {
+    /// Kind of channel member, from `Author::official_profile_type`
+    pub enum ProfileType {
+        /// Artist of the channel
+        Star => "STAR",
+        /// Official staff account of the channel
+        Official => "OFFICIAL",
+        /// Regular channel member
+        Fan => "NONE" | "USER" | "",
+    }
+}
+
+/// Channel name and code included in posts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialChannel {
+    pub channel_code: String,
+    pub channel_name: String,
+}
+
+impl From<&Channel> for PartialChannel {
+    fn from(channel: &Channel) -> Self {
+        Self {
+            channel_code: channel.channel_code.clone(),
+            channel_name: channel.channel_name.clone(),
+        }
+    }
+}
+
+impl From<&ChannelInfo> for PartialChannel {
+    fn from(channel: &ChannelInfo) -> Self {
+        Self {
+            channel_code: channel.channel_code.clone(),
+            channel_name: channel.channel_name.clone(),
+        }
+    }
+}
+
+/// Board information included in posts. Which fields are present depends on
+/// where the post came from.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialBoard {
+    pub board_id: i64,
+    pub title: Option<String>,
+    pub board_type: Option<BoardType>,
+    pub open_type: Option<OpenType>,
+    pub pay_required: bool,
+}
+
+impl From<&grouped_board::Board> for PartialBoard {
+    fn from(board: &grouped_board::Board) -> Self {
+        Self {
+            board_id: board.board_id,
+            title: Some(board.title.clone()),
+            board_type: Some(board.board_type),
+            open_type: Some(board.open_type.clone()),
+            pay_required: board.pay_required,
+        }
+    }
+}
+
+/// Fields common to posts from `get_video` (`PostDetail`) and
+/// `get_board_posts` (`board_posts::Post`)
+pub trait PostInfo {
+    fn post_id(&self) -> &str;
+    fn url(&self) -> &str;
+    fn title(&self) -> &str;
+    fn author(&self) -> &Author;
+    fn created_at(&self) -> DateTime<Utc>;
+    fn comment_count(&self) -> i64;
+    fn emotion_count(&self) -> i64;
+    fn attachments(&self) -> Option<&Attachments>;
+    /// Video in this post, None for text and photo posts
+    fn official_video(&self) -> Option<&OfficialVideo>;
+}
+
+impl PostInfo for PostDetail {
+    fn post_id(&self) -> &str {
+        &self.post_id
+    }
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn author(&self) -> &Author {
+        &self.author
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn comment_count(&self) -> i64 {
+        self.comment_count.unwrap_or_default()
+    }
+
+    fn emotion_count(&self) -> i64 {
+        self.emotion_count.unwrap_or_default()
+    }
+
+    fn attachments(&self) -> Option<&Attachments> {
+        self.attachments.as_ref()
+    }
+
+    fn official_video(&self) -> Option<&OfficialVideo> {
+        Some(&self.official_video)
+    }
+}
+
+impl PostInfo for board_posts::Post {
+    fn post_id(&self) -> &str {
+        &self.post_id
+    }
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn author(&self) -> &Author {
+        &self.author
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn comment_count(&self) -> i64 {
+        self.comment_count
+    }
+
+    fn emotion_count(&self) -> i64 {
+        self.emotion_count
+    }
+
+    fn attachments(&self) -> Option<&Attachments> {
+        Some(&self.attachments)
+    }
+
+    fn official_video(&self) -> Option<&OfficialVideo> {
+        self.official_video.as_deref()
+    }
+}
@@ -1,6 +1,9 @@
+use super::helpers::string_enum;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BoardType {
     Notice,
@@ -9,16 +12,24 @@ pub enum BoardType {
     VlivePlus,
 }
 
+string_enum! {
+    /// Who can view a board
+    pub enum OpenType {
+        Public => "PUBLIC",
+        Private => "PRIVATE",
+    }
+}
+
 pub type GroupedBoards = Vec<GroupedBoard>;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GroupedBoard {
     pub group_title: String,
     pub boards: Vec<Board>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Board {
     pub board_id: i64,
@@ -27,7 +38,19 @@ pub struct Board {
     pub use_star_filter: bool,
     pub pay_required: bool,
     pub expose: bool,
-    pub open_type: String,
-    pub last_updated_at: i64,
+    pub open_type: OpenType,
+    #[serde(with = "ts_milliseconds")]
+    pub last_updated_at: DateTime<Utc>,
     pub channel_code: String,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
+}
+
+impl Board {
+    /// Checks if this board requires a VLIVE+ purchase
+    pub fn is_paid(&self) -> bool {
+        self.pay_required || self.board_type == BoardType::VlivePlus
+    }
 }
@@ -1,5 +1,7 @@
-use chrono::{offset::FixedOffset, DateTime};
-use serde::{de, Deserialize, Deserializer};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{de, de::DeserializeOwned, Deserialize, Deserializer};
+
+use crate::time::kst;
 
 pub fn bool_from_str<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
@@ -9,31 +11,132 @@ where
     Ok(s == "Y")
 }
 
-pub fn timestamp_from_str<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+/// Parses a `%Y-%m-%d %H:%M:%S` date string, all dates on VLive are in KST
+fn parse_kst(s: &str) -> chrono::ParseResult<DateTime<Utc>> {
+    kst()
+        .datetime_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+pub fn timestamp_from_str<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
 
-    // All dates on VLive are KST timezone
-    let with_timezone = format!("{} +0900", s);
-    DateTime::parse_from_str(&with_timezone, "%Y-%m-%d %H:%M:%S %z").map_err(de::Error::custom)
+    parse_kst(&s).map_err(de::Error::custom)
 }
 
-pub fn option_timestamp_from_str<'de, D>(
-    deserializer: D,
-) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+pub fn option_timestamp_from_str<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s: Option<String> = Option::deserialize(deserializer)?;
 
-    if let Some(s) = s {
-        let with_timezone = format!("{} +0900", s);
-        let dt = DateTime::parse_from_str(&with_timezone, "%Y-%m-%d %H:%M:%S %z")
-            .map_err(de::Error::custom)?;
-        Ok(Some(dt))
-    } else {
-        Ok(None)
+    s.map(|s| parse_kst(&s).map_err(de::Error::custom))
+        .transpose()
+}
+
+/// Parses a single value, logging and returning None if it doesn't match `T`
+fn lenient_value<T: DeserializeOwned>(value: serde_json::Value) -> Option<T> {
+    match serde_json::from_value(value) {
+        Ok(v) => Some(v),
+        Err(e) => {
+            tracing::debug!(error = %e, "Ignoring malformed value");
+            None
+        }
     }
 }
+
+/// Parses each element of a list on its own, elements that fail to parse are
+/// None instead of failing the whole list
+pub fn vec_of_lenient<'de, D, T>(deserializer: D) -> Result<Vec<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let values: Option<Vec<serde_json::Value>> = Option::deserialize(deserializer)?;
+
+    Ok(values
+        .unwrap_or_default()
+        .into_iter()
+        .map(lenient_value)
+        .collect())
+}
+
+/// Parses an optional value, None if it fails to parse
+pub fn option_lenient<'de, D, T>(deserializer: D) -> Result<Option<Box<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value: Option<serde_json::Value> = Option::deserialize(deserializer)?;
+
+    Ok(value.and_then(lenient_value).map(Box::new))
+}
+
+/// Defines an enum for a string field returned by VLive, with an `Unknown`
+/// variant for values that aren't known yet. Other values that parse into the
+/// same variant can be listed after the first with `|`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal $(| $alias:literal)*,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Value not known by this library
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Value as returned by VLive
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(s) => s,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self {
+                match s {
+                    $($value $(| $alias)* => Self::$variant,)*
+                    other => Self::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                Ok(Self::from(s.as_str()))
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// The logged in VLive member
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Member {
+    pub member_id: String,
+    pub nickname: String,
+    pub profile_image_url: Option<String>,
+    /// Country code of the account, such as `US`
+    pub country_code: Option<String>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
+}
@@ -1,6 +1,14 @@
 pub mod board_posts;
 pub mod channel;
+pub mod comment;
+pub mod common;
 pub mod grouped_board;
 pub(crate) mod helpers;
+pub mod member;
 pub mod recent_video;
+pub mod search;
 pub mod video;
+
+/// Fields returned by VLive that aren't modelled yet
+#[cfg(feature = "extra-fields")]
+pub type Extra = std::collections::HashMap<String, serde_json::Value>;
@@ -6,7 +6,9 @@ use std::result::Result as StdResult;
 
 use super::channel::ChannelType;
 use super::video::VideoType;
+use crate::time::parse_posted_age;
 use crate::Result;
+use chrono::{DateTime, Utc};
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct RecentVideo {
@@ -38,10 +40,26 @@ pub struct RecentVideo {
     /// May be missing for some videos
     #[serde(default, deserialize_with = "some_u64_from_str")]
     pub likes: Option<u64>,
+
+    /// When this was posted as shown on the page, such as `3 hours ago` or
+    /// `Jan 2, 2021`
+    #[serde(default)]
+    pub posted_age: Option<String>,
+
+    /// Approximate publish time parsed from `posted_age`, relative to when
+    /// the page was fetched
+    #[serde(skip)]
+    pub published_at: Option<DateTime<Utc>>,
 }
 
 impl RecentVideo {
     pub fn from_html(html: &str) -> Result<Vec<Self>> {
+        Self::from_html_at(html, Utc::now())
+    }
+
+    /// Parses videos from HTML fetched at `fetched_at`, used as the reference
+    /// time for relative posted ages
+    pub fn from_html_at(html: &str, fetched_at: DateTime<Utc>) -> Result<Vec<Self>> {
         let fragment = Html::parse_fragment(html);
         let selector = Selector::parse("li").unwrap();
 
@@ -142,7 +160,14 @@ impl RecentVideo {
             let video = serde_json::from_value::<RecentVideo>(val);
 
             match video {
-                Ok(v) => videos.push(v),
+                Ok(mut v) => {
+                    v.published_at = v
+                        .posted_age
+                        .as_deref()
+                        .and_then(|age| parse_posted_age(age, fetched_at));
+
+                    videos.push(v)
+                }
                 Err(e) => {
                     tracing::error!(
                         ?video_attrs,
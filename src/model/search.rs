@@ -0,0 +1,23 @@
+use super::board_posts::Paging;
+use super::video::{OfficialVideo, PostDetail};
+use serde::{Deserialize, Serialize};
+
+/// Videos in a channel matching a search query
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VideoSearchResults {
+    pub paging: Paging,
+    /// Video posts, each containing the full `OfficialVideo`
+    pub data: Vec<PostDetail>,
+}
+
+impl VideoSearchResults {
+    /// Iterates over the videos found
+    pub fn videos(&self) -> impl Iterator<Item = &OfficialVideo> {
+        self.data.iter().map(|p| p.official_video.as_ref())
+    }
+
+    /// Cursor for the next page of results, None if this is the last page
+    pub fn next_after(&self) -> Option<&str> {
+        self.paging.next_params.as_ref().map(|p| p.after.as_str())
+    }
+}
@@ -1,12 +1,90 @@
 use super::channel;
-use chrono::naive::serde::ts_milliseconds;
-use chrono::NaiveDateTime;
+use super::common::{PartialBoard, PartialChannel};
+use super::helpers::string_enum;
+use chrono::serde::ts_milliseconds;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum VideoType {
-    VOD,
-    LIVE,
+pub use super::board_posts::Attachments;
+pub use super::common::Author;
+
+string_enum! {
+    pub enum VideoType {
+        VOD => "VOD",
+        LIVE => "LIVE",
+    }
+}
+
+string_enum! {
+    /// If a video is free or a paid VLIVE+ product
+    pub enum ProductType {
+        None => "NONE",
+        Paid => "PAID",
+    }
+}
+
+impl ProductType {
+    pub fn is_paid(&self) -> bool {
+        *self == Self::Paid
+    }
+}
+
+string_enum! {
+    pub enum ScreenOrientation {
+        Horizontal => "HORIZONTAL",
+        Vertical => "VERTICAL",
+    }
+}
+
+impl ScreenOrientation {
+    pub fn is_vertical(&self) -> bool {
+        *self == Self::Vertical
+    }
+}
+
+string_enum! {
+    /// If a video is visible on VLive
+    pub enum ExposeStatus {
+        Exposed => "EXPOSED",
+        Hidden => "HIDDEN",
+    }
+}
+
+string_enum! {
+    pub enum EncodingStatus {
+        Encoding => "ENCODING",
+        Complete => "COMPLETE",
+    }
+}
+
+string_enum! {
+    pub enum VodSecureStatus {
+        None => "NONE",
+        Complete => "COMPLETE",
+    }
+}
+
+string_enum! {
+    pub enum VrContentType {
+        None => "NONE",
+        Vr => "VR",
+        Vr180 => "VR_180",
+        Vr360 => "VR_360",
+    }
+}
+
+impl VrContentType {
+    pub fn is_vr(&self) -> bool {
+        !matches!(self, Self::None | Self::Unknown(_))
+    }
+}
+
+string_enum! {
+    pub enum LiveStatus {
+        Reserved => "RESERVED",
+        OnAir => "ON_AIR",
+        Ended => "ENDED",
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -52,6 +130,10 @@ pub struct VideoMeta {
     pub share: Share,
     pub user: User,
     pub api_list: Vec<ApiListItem>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -194,7 +276,7 @@ pub struct LiveStreamResolution {
 #[serde(rename_all = "camelCase")]
 pub struct LiveStreamInfo {
     pub use_key: bool,
-    pub live_status: String,
+    pub live_status: LiveStatus,
     pub resolutions: Vec<LiveStreamResolution>,
 }
 
@@ -204,8 +286,8 @@ pub struct VideoKey {
     // adParams field ignored
 }
 
-#[serde(rename_all = "camelCase")]
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoState {
     pub post_detail: Post,
     pub channel: channel::ChannelWrapper,
@@ -273,13 +355,14 @@ pub struct PostDetail {
     pub title: String,
     pub author: Author,
     pub author_id: Option<String>,
-    pub created_at: i64,
+    #[serde(with = "ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
     pub url: String,
     pub attachments: Option<Attachments>,
     pub available_actions: Vec<String>,
     pub board_id: Option<i64>,
     pub channel_code: Option<String>,
-    pub channel: Option<channel::PartialChannel>,
+    pub channel: Option<PartialChannel>,
     pub content_type: Option<String>,
     pub comment_count: Option<i64>,
     pub emotion_count: Option<i64>,
@@ -288,6 +371,10 @@ pub struct PostDetail {
     pub is_viewer_bookmarked: Option<bool>,
     pub official_video: Box<OfficialVideo>,
     pub post_version: Option<String>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
 }
 
 /// Post details in a related video
@@ -295,35 +382,12 @@ pub struct PostDetail {
 #[serde(rename_all = "camelCase")]
 pub struct PartialPostDetail {
     pub post_id: String,
-    pub channel: channel::PartialChannel,
-    pub board: BoardInfo,
+    pub channel: PartialChannel,
+    pub board: PartialBoard,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct BoardInfo {
-    pub board_id: i64,
-    pub open_type: String,
-    pub pay_required: bool,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Attachments {
-    pub video_count: i64,
-    pub photo_count: i64,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Author {
-    pub member_id: String,
-    pub channel_code: String,
-    pub joined: bool,
-    pub nickname: String,
-    pub profile_image_url: String,
-    pub official_profile_type: String,
-}
+#[deprecated(note = "Use `common::PartialBoard`")]
+pub type BoardInfo = PartialBoard;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -338,24 +402,24 @@ pub struct OfficialVideo {
     pub like_count: i64,
     pub comment_count: i64,
     pub thumb: String,
-    pub expose_status: String,
-    pub screen_orientation: Option<String>,
+    pub expose_status: ExposeStatus,
+    pub screen_orientation: Option<ScreenOrientation>,
 
     #[serde(with = "ts_milliseconds")]
-    pub will_start_at: NaiveDateTime,
+    pub will_start_at: DateTime<Utc>,
 
     #[serde(with = "ts_milliseconds")]
-    pub on_air_start_at: NaiveDateTime,
+    pub on_air_start_at: DateTime<Utc>,
 
     #[serde(with = "ts_milliseconds")]
-    pub will_end_at: NaiveDateTime,
+    pub will_end_at: DateTime<Utc>,
 
     #[serde(with = "ts_milliseconds")]
-    pub created_at: NaiveDateTime,
+    pub created_at: DateTime<Utc>,
     pub live_thumb_yn: Option<bool>,
     pub upcoming_yn: Option<bool>,
-    pub product_type: Option<String>,
-    pub vr_content_type: Option<String>,
+    pub product_type: Option<ProductType>,
+    pub vr_content_type: Option<VrContentType>,
 
     #[serde(default)]
     pub badges: Vec<String>,
@@ -373,8 +437,83 @@ pub struct OfficialVideo {
     /// VOD ID, None if live video
     pub vod_id: Option<String>,
     pub play_time: Option<i64>,
-    pub encoding_status: Option<String>,
-    pub vod_secure_status: Option<String>,
+    pub encoding_status: Option<EncodingStatus>,
+    pub vod_secure_status: Option<VodSecureStatus>,
+    /// Unmodelled fields, only kept with the `extra-fields` feature
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: super::Extra,
+}
+
+impl OfficialVideo {
+    pub fn is_live(&self) -> bool {
+        self.kind == VideoType::LIVE
+    }
+
+    /// Checks if this is a paid VLIVE+ video
+    pub fn is_paid(&self) -> bool {
+        self.product_type
+            .as_ref()
+            .map(ProductType::is_paid)
+            .unwrap_or(false)
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.screen_orientation
+            .as_ref()
+            .map(ScreenOrientation::is_vertical)
+            .unwrap_or(false)
+    }
+
+    pub fn is_vr(&self) -> bool {
+        self.vr_content_type
+            .as_ref()
+            .map(VrContentType::is_vr)
+            .unwrap_or(false)
+    }
+
+    /// Checks if the VOD has finished encoding and is ready to play
+    pub fn is_encoded(&self) -> bool {
+        self.encoding_status == Some(EncodingStatus::Complete)
+    }
+
+    /// Title in `locale`, such as `ko_KR`, falling back to `en_US`, then the
+    /// default multinational title, then `title`
+    pub fn title_for(&self, locale: &str) -> &str {
+        self.title_for_locales(&[locale, "en_US"])
+    }
+
+    /// Title in the first available locale of `locales`, falling back to the
+    /// default multinational title, then `title`. Locales also match titles
+    /// in the same language, so `ko` or `ko_KR` both match a `ko_KR` title.
+    pub fn title_for_locales(&self, locales: &[&str]) -> &str {
+        locales
+            .iter()
+            .find_map(|locale| self.multinational_title(locale))
+            .or_else(|| self.multinational_titles.iter().find(|t| t.is_default()))
+            .map(|t| t.label.as_str())
+            .unwrap_or(&self.title)
+    }
+
+    /// Multinational title in `locale`, or in the same language if there
+    /// isn't an exact match
+    pub fn multinational_title(&self, locale: &str) -> Option<&MultinationalTitle> {
+        let language = locale.split('_').next().unwrap_or(locale);
+
+        self.multinational_titles
+            .iter()
+            .find(|t| t.locale.eq_ignore_ascii_case(locale))
+            .or_else(|| {
+                self.multinational_titles
+                    .iter()
+                    .find(|t| t.language().eq_ignore_ascii_case(language))
+            })
+    }
+
+    /// Locales with a multinational title
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.multinational_titles.iter().map(|t| t.locale.as_str())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -398,3 +537,16 @@ pub struct MultinationalTitle {
     pub label: String,
     pub default_yn: Option<bool>,
 }
+
+impl MultinationalTitle {
+    /// Language part of the locale, such as `ko` for `ko_KR`
+    pub fn language(&self) -> &str {
+        self.locale.split('_').next().unwrap_or(&self.locale)
+    }
+
+    /// Checks if this is the title shown when there's no title in the
+    /// viewer's locale
+    pub fn is_default(&self) -> bool {
+        self.default_yn.unwrap_or(false)
+    }
+}
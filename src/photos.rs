@@ -0,0 +1,64 @@
+use reqwest::Client;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::download::{download_file, file_exists};
+use crate::error::Result;
+use crate::model::board_posts::{BoardPosts, Photo, Post};
+
+/// Downloads all photos in a post into `dir`, returning paths of newly
+/// downloaded files.
+///
+/// Photos are saved in original resolution with names from
+/// `Photo::file_name`, and photos that already exist in `dir` are skipped.
+/// Downloads are written to a `.part` file first, so a photo that failed to
+/// download is retried on the next call.
+pub async fn download_post_photos<P: AsRef<Path>>(
+    client: &Client,
+    post: &Post,
+    dir: P,
+) -> Result<Vec<PathBuf>> {
+    download_photos(client, post.attachments.photo.values(), dir.as_ref()).await
+}
+
+/// Downloads all photos in a page of board posts into `dir`, returning paths
+/// of newly downloaded files. Photos shared between posts are only downloaded
+/// once.
+pub async fn download_board_photos<P: AsRef<Path>>(
+    client: &Client,
+    posts: &BoardPosts,
+    dir: P,
+) -> Result<Vec<PathBuf>> {
+    let photos = posts.data.iter().flat_map(|p| p.attachments.photo.values());
+
+    download_photos(client, photos, dir.as_ref()).await
+}
+
+async fn download_photos<'a, I>(client: &Client, photos: I, dir: &Path) -> Result<Vec<PathBuf>>
+where
+    I: Iterator<Item = &'a Photo>,
+{
+    fs::create_dir_all(dir).await?;
+
+    let mut seen = HashSet::new();
+    let mut downloaded = Vec::new();
+
+    for photo in photos {
+        if !seen.insert(photo.photo_id.as_str()) {
+            continue;
+        }
+
+        let path = dir.join(photo.file_name());
+
+        if file_exists(&path).await {
+            tracing::debug!(?path, "Photo already downloaded, skipping");
+            continue;
+        }
+
+        download_file(client, photo.original_url(), &path).await?;
+        downloaded.push(path);
+    }
+
+    Ok(downloaded)
+}
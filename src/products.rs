@@ -0,0 +1,88 @@
+//! VLIVE+ paid videos grouped by the products they are sold in.
+//!
+//! Packages only carry the product ID and the videos found in the channel's
+//! video list. Product details such as the name and price aren't available,
+//! there's no product endpoint with a known response to model them from.
+
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::model::{
+    channel::VideoListItem,
+    grouped_board::{Board, GroupedBoards},
+};
+use crate::query::VideoQuery;
+use crate::{ChannelId, VLiveRequester};
+
+/// Paid videos sold together in a package, or a single video sold on its own
+#[derive(Debug, Clone)]
+pub struct PaidPackage {
+    /// `package_product_id` of the videos, or the `product_id` of a video
+    /// sold on its own
+    pub product_id: String,
+    pub videos: Vec<VideoListItem>,
+}
+
+impl PaidPackage {
+    /// Checks if this is a package of multiple videos rather than a single
+    /// video
+    pub fn is_package(&self) -> bool {
+        self.videos
+            .iter()
+            .any(|v| v.package_product_id == self.product_id)
+    }
+}
+
+/// Gets every paid video of a channel grouped by the package it's sold in,
+/// in order of each package's newest video.
+pub async fn get_paid_packages<R>(requester: &R, channel: &ChannelId) -> Result<Vec<PaidPackage>>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let videos = VideoQuery::new()
+        .paid(true)
+        .include_upcoming(true)
+        .fetch(requester, channel)
+        .await?;
+
+    Ok(group_by_package(videos))
+}
+
+/// Groups paid videos by `package_product_id`, or by `product_id` for videos
+/// that aren't in a package. Free videos are ignored.
+pub fn group_by_package<I>(videos: I) -> Vec<PaidPackage>
+where
+    I: IntoIterator<Item = VideoListItem>,
+{
+    let mut packages: Vec<PaidPackage> = Vec::new();
+    let mut index = HashMap::new();
+
+    for video in videos.into_iter().filter(|v| v.is_paid()) {
+        let product_id = if video.package_product_id.is_empty() {
+            video.product_id.clone()
+        } else {
+            video.package_product_id.clone()
+        };
+
+        let pos = *index.entry(product_id.clone()).or_insert_with(|| {
+            packages.push(PaidPackage {
+                product_id,
+                videos: Vec::new(),
+            });
+            packages.len() - 1
+        });
+
+        packages[pos].videos.push(video);
+    }
+
+    packages
+}
+
+/// Boards that require a VLIVE+ purchase
+pub fn paid_boards(grouped_boards: &GroupedBoards) -> Vec<&Board> {
+    grouped_boards
+        .iter()
+        .flat_map(|g| g.boards.iter())
+        .filter(|b| b.is_paid())
+        .collect()
+}
@@ -0,0 +1,226 @@
+//! Filtered queries over a channel's video list.
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::model::{
+    channel::VideoListItem,
+    video::{ScreenOrientation, VideoType},
+};
+use crate::{ChannelId, VLiveRequester};
+
+/// Builder for filtering a channel's videos, fetching pages of
+/// `get_channel_video_list` until enough videos match.
+///
+/// ```no_run
+/// # async fn run() -> vlive::error::Result<()> {
+/// use vlive::model::video::VideoType;
+/// use vlive::query::VideoQuery;
+///
+/// let client = reqwest::Client::new();
+/// let lives = VideoQuery::new()
+///     .video_type(VideoType::LIVE)
+///     .min_play_time(30 * 60)
+///     .limit(10)
+///     .fetch(&client, &"FE619".into())
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct VideoQuery {
+    video_type: Option<VideoType>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    paid: Option<bool>,
+    orientation: Option<ScreenOrientation>,
+    min_play_time: Option<u32>,
+    title: Option<Regex>,
+    include_upcoming: bool,
+    limit: Option<usize>,
+    page_size: u32,
+}
+
+impl Default for VideoQuery {
+    fn default() -> Self {
+        Self {
+            video_type: None,
+            from: None,
+            to: None,
+            paid: None,
+            orientation: None,
+            min_play_time: None,
+            title: None,
+            include_upcoming: false,
+            limit: None,
+            page_size: 100,
+        }
+    }
+}
+
+impl VideoQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only live or only VOD videos
+    pub fn video_type(mut self, video_type: VideoType) -> Self {
+        self.video_type = Some(video_type);
+        self
+    }
+
+    /// Videos that went on air at or after `from`
+    pub fn from(mut self, from: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Videos that went on air before `to`
+    pub fn to(mut self, to: DateTime<Utc>) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Only paid VLIVE+ videos if true, only free videos if false
+    pub fn paid(mut self, paid: bool) -> Self {
+        self.paid = Some(paid);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: ScreenOrientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    /// Videos at least `secs` seconds long
+    pub fn min_play_time(mut self, secs: u32) -> Self {
+        self.min_play_time = Some(secs);
+        self
+    }
+
+    /// Videos with titles matching the regex `pattern`, errors if the pattern
+    /// is invalid
+    pub fn title(mut self, pattern: &str) -> Result<Self> {
+        let regex = Regex::new(pattern)
+            .map_err(|e| Error::Vlive(format!("Invalid title pattern: {}", e)))?;
+        self.title = Some(regex);
+
+        Ok(self)
+    }
+
+    /// Include upcoming videos that haven't started yet, excluded by default
+    pub fn include_upcoming(mut self, include: bool) -> Self {
+        self.include_upcoming = include;
+        self
+    }
+
+    /// Stop fetching once this many videos match
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Number of videos to request per page, defaults to 100
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Checks if a video matches every filter of this query
+    pub fn matches(&self, video: &VideoListItem) -> bool {
+        if video.upcoming_yn && !self.include_upcoming {
+            return false;
+        }
+
+        if let Some(video_type) = &self.video_type {
+            if video.video_type != *video_type {
+                return false;
+            }
+        }
+
+        if let Some(from) = self.from {
+            if video.on_air_start_at < from {
+                return false;
+            }
+        }
+
+        if let Some(to) = self.to {
+            if video.on_air_start_at >= to {
+                return false;
+            }
+        }
+
+        if let Some(paid) = self.paid {
+            if video.is_paid() != paid {
+                return false;
+            }
+        }
+
+        if let Some(orientation) = &self.orientation {
+            if video.screen_orientation != *orientation {
+                return false;
+            }
+        }
+
+        if let Some(min_play_time) = self.min_play_time {
+            if video.play_time < min_play_time {
+                return false;
+            }
+        }
+
+        match &self.title {
+            Some(re) => re.is_match(&video.title),
+            None => true,
+        }
+    }
+
+    /// Fetches pages of the channel's videos until `limit` videos match or
+    /// there are no more videos, returning matches in upload order, newest
+    /// first.
+    ///
+    /// Since videos are listed newest first, paging stops early once a whole
+    /// page went on air before `from`.
+    pub async fn fetch<R>(&self, requester: &R, channel: &ChannelId) -> Result<Vec<VideoListItem>>
+    where
+        R: VLiveRequester + Sync + ?Sized,
+    {
+        let mut matched = Vec::new();
+        let mut page_no = 1;
+        let mut seen = 0;
+
+        loop {
+            let list = requester
+                .get_channel_video_list(channel, self.page_size, page_no)
+                .await?;
+
+            if list.video_list.is_empty() {
+                break;
+            }
+
+            seen += list.video_list.len();
+            let past_from = match self.from {
+                Some(from) => list.video_list.iter().all(|v| v.on_air_start_at < from),
+                None => false,
+            };
+
+            for video in list.video_list {
+                if self.matches(&video) {
+                    matched.push(video);
+                }
+
+                if matches!(self.limit, Some(limit) if matched.len() >= limit) {
+                    return Ok(matched);
+                }
+            }
+
+            if past_from || seen >= list.total_video_count as usize {
+                break;
+            }
+
+            page_no += 1;
+        }
+
+        Ok(matched)
+    }
+}
@@ -0,0 +1,64 @@
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+
+/// A response from VLive along with the parsed value.
+///
+/// The body is kept even if parsing fails, which is useful for logging
+/// payloads or building fixtures when VLive changes their responses.
+#[derive(Debug)]
+pub struct RawResponse<T> {
+    /// HTTP status code of the response
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Full response body, either JSON or HTML depending on the endpoint
+    pub body: String,
+    /// Value parsed from the body
+    pub value: Result<T>,
+}
+
+impl<T> RawResponse<T> {
+    /// Reads the body of a response and parses it with the given function,
+    /// such as `raw::json`
+    pub async fn from_response<F>(response: Response, parse: F) -> Result<Self>
+    where
+        F: FnOnce(&str) -> Result<T>,
+    {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+        let value = parse(&body);
+
+        Ok(Self {
+            status,
+            headers,
+            body,
+            value,
+        })
+    }
+
+    /// Discards the raw response and returns the parsed value
+    pub fn into_value(self) -> Result<T> {
+        self.value
+    }
+
+    /// Maps the parsed value while keeping the raw response
+    pub fn map<U, F>(self, f: F) -> RawResponse<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        RawResponse {
+            status: self.status,
+            headers: self.headers,
+            body: self.body,
+            value: self.value.map(f),
+        }
+    }
+}
+
+/// Parses a JSON body
+pub fn json<T: DeserializeOwned>(body: &str) -> Result<T> {
+    serde_json::from_str(body).map_err(From::from)
+}
@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::model::board_posts::Post;
+use crate::VLiveRequester;
+
+/// Follows a repost's share chain and fetches the original post.
+///
+/// Returns the given post if it isn't a repost. Partial `origin_post`s are
+/// used to skip to the furthest known post in the chain, so usually only the
+/// original post is fetched.
+pub async fn resolve_origin_post<R>(requester: &R, post: Post) -> Result<Post>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let mut post = post;
+    let mut seen = HashSet::new();
+    seen.insert(post.post_id.clone());
+
+    while let Some(origin) = post.origin_post.take() {
+        let origin_id = origin.original_post_id().to_string();
+
+        if !seen.insert(origin_id.clone()) {
+            return Err(Error::Vlive(format!(
+                "Share chain of post {} has a cycle",
+                post.post_id
+            )));
+        }
+
+        post = requester.get_post(&origin_id).await?;
+    }
+
+    Ok(post)
+}
@@ -0,0 +1,233 @@
+//! Logged in sessions using cookies from a browser.
+//!
+//! Members-only boards, purchased VLIVE+ videos and fanship content need the
+//! VLive session cookie `NEO_SES` along with the Naver login cookies
+//! `NID_AUT` and `NID_SES`. These can be exported from a logged in browser in
+//! the Netscape `cookies.txt` format and loaded with
+//! `Session::from_cookie_file`.
+
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::cookie::Jar;
+use reqwest::{Client, Url};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+use crate::model::member::Member;
+use crate::VLiveRequester;
+
+/// Domains that cookies are kept for, other cookies in a cookie file are
+/// ignored
+const COOKIE_DOMAINS: &[&str] = &["vlive.tv", "naver.com"];
+
+/// A cookie from a cookie file
+#[derive(Clone, PartialEq)]
+pub struct Cookie {
+    pub domain: String,
+    /// If the cookie is also sent to subdomains of `domain`
+    pub include_subdomains: bool,
+    pub path: String,
+    pub secure: bool,
+    /// None for session cookies
+    pub expires: Option<DateTime<Utc>>,
+    pub name: String,
+    pub value: String,
+}
+
+impl Cookie {
+    /// Secure cookie for `.vlive.tv`, such as `NEO_SES`
+    pub fn vlive(name: &str, value: &str) -> Self {
+        Self {
+            domain: ".vlive.tv".to_string(),
+            include_subdomains: true,
+            path: "/".to_string(),
+            secure: true,
+            expires: None,
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        matches!(self.expires, Some(expires) if expires <= Utc::now())
+    }
+
+    fn url(&self) -> Option<Url> {
+        let host = self.domain.trim_start_matches('.');
+
+        Url::parse(&format!("https://{}{}", host, self.path)).ok()
+    }
+
+    fn set_cookie_str(&self) -> String {
+        let mut s = format!("{}={}; Path={}", self.name, self.value, self.path);
+
+        // Cookies without a domain attribute are only sent to the exact host
+        if self.include_subdomains {
+            s.push_str("; Domain=");
+            s.push_str(&self.domain);
+        }
+
+        if self.secure {
+            s.push_str("; Secure");
+        }
+
+        s
+    }
+}
+
+impl fmt::Debug for Cookie {
+    // Values are left out so session cookies don't end up in logs
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cookie")
+            .field("domain", &self.domain)
+            .field("path", &self.path)
+            .field("expires", &self.expires)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// Parses cookies in the Netscape `cookies.txt` format, skipping comments and
+/// malformed lines
+pub fn parse_cookie_file(contents: &str) -> Vec<Cookie> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            // Only line endings are trimmed, since an empty value leaves a
+            // trailing tab
+            let line = line.trim_end_matches(&['\r', '\n'][..]);
+            // curl and browser extensions mark HttpOnly cookies with a prefix
+            let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                return None;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+
+            if fields.len() != 7 {
+                return None;
+            }
+
+            let expires = fields[4].parse::<i64>().ok()?;
+
+            Some(Cookie {
+                domain: fields[0].to_string(),
+                include_subdomains: fields[1].eq_ignore_ascii_case("TRUE"),
+                path: fields[2].to_string(),
+                secure: fields[3].eq_ignore_ascii_case("TRUE"),
+                expires: if expires > 0 {
+                    Some(Utc.timestamp(expires, 0))
+                } else {
+                    None
+                },
+                name: fields[5].to_string(),
+                value: fields[6].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A logged in VLive session.
+///
+/// Cookies are attached to requests made with `Session::client`, which can
+/// be used anywhere a `VLiveRequester` is needed.
+#[derive(Clone)]
+pub struct Session {
+    client: Client,
+    cookie_names: Vec<String>,
+}
+
+impl Session {
+    /// Creates a session from cookies, ignoring expired cookies and cookies
+    /// for domains other than VLive and Naver
+    pub fn from_cookies<I>(cookies: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = Cookie>,
+    {
+        let jar = Jar::default();
+        let mut cookie_names = Vec::new();
+
+        for cookie in cookies {
+            let host = cookie.domain.trim_start_matches('.');
+            let is_vlive = COOKIE_DOMAINS
+                .iter()
+                .any(|d| host == *d || host.ends_with(&format!(".{}", d)));
+
+            if !is_vlive {
+                continue;
+            }
+
+            if cookie.is_expired() {
+                tracing::warn!(name = %cookie.name, "Skipping expired cookie");
+                continue;
+            }
+
+            if let Some(url) = cookie.url() {
+                jar.add_cookie_str(&cookie.set_cookie_str(), &url);
+                cookie_names.push(cookie.name);
+            }
+        }
+
+        if cookie_names.is_empty() {
+            return Err(Error::from("No VLive or Naver cookies found"));
+        }
+
+        let client = Client::builder().cookie_provider(Arc::new(jar)).build()?;
+
+        Ok(Self {
+            client,
+            cookie_names,
+        })
+    }
+
+    /// Creates a session from a Netscape `cookies.txt` file
+    pub fn from_cookie_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+
+        Self::from_cookies(parse_cookie_file(&contents))
+    }
+
+    /// Creates a session from a `Cookie` header value copied from a browser,
+    /// such as `NEO_SES=...; NID_AUT=...`. Cookies are sent to all of VLive.
+    pub fn from_cookie_header(header: &str) -> Result<Self> {
+        let cookies = header.split(';').filter_map(|pair| {
+            let (name, value) = pair.trim().split_once('=')?;
+
+            Some(Cookie::vlive(name.trim(), value.trim()))
+        });
+
+        Self::from_cookies(cookies)
+    }
+
+    /// Client that sends this session's cookies
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Names of the cookies in this session
+    pub fn cookie_names(&self) -> &[String] {
+        &self.cookie_names
+    }
+
+    /// Checks if the session has the VLive session cookie `NEO_SES`
+    pub fn has_session_cookie(&self) -> bool {
+        self.cookie_names.iter().any(|n| n == "NEO_SES")
+    }
+
+    /// Gets the logged in member, erroring if the cookies are expired or
+    /// invalid
+    pub async fn whoami(&self) -> Result<Member> {
+        self.client.get_current_member().await
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("cookie_names", &self.cookie_names)
+            .finish()
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::error::Result;
+use crate::model::{
+    board_posts::{Post, StarReaction},
+    comment::Comment,
+    common::Author,
+};
+use crate::{ChannelId, VLiveRequester};
+
+/// Something a star (artist) did in a channel
+#[derive(Debug)]
+pub enum StarActivity {
+    /// A post written by a star
+    Post(Box<Post>),
+    /// A star's comment on a post
+    Comment {
+        post_id: String,
+        comment: Box<Comment>,
+    },
+    /// A star's reaction to a post
+    Reaction {
+        post_id: String,
+        reaction: StarReaction,
+    },
+}
+
+impl StarActivity {
+    /// Star who did this
+    pub fn author(&self) -> &Author {
+        match self {
+            Self::Post(post) => &post.author,
+            Self::Comment { comment, .. } => &comment.author,
+            Self::Reaction { reaction, .. } => &reaction.member,
+        }
+    }
+
+    /// Post this activity is on
+    pub fn post_id(&self) -> &str {
+        match self {
+            Self::Post(post) => &post.post_id,
+            Self::Comment { post_id, .. } | Self::Reaction { post_id, .. } => post_id,
+        }
+    }
+
+    /// Creation time. Reactions without a time use the time of the post
+    /// they are on
+    pub fn created_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::Post(post) => post.created_at,
+            Self::Comment { comment, .. } => comment.created_at,
+            Self::Reaction { reaction, .. } => reaction
+                .created_at
+                .unwrap_or_else(|| Utc.timestamp_millis(0)),
+        }
+    }
+}
+
+/// Gets recent star posts, comments and reactions across all of a channel's
+/// boards, newest first.
+///
+/// Only the latest page of posts in each board is checked, and up to
+/// `comments_per_post` star comments are fetched for each post. Boards that
+/// require payment are skipped.
+pub async fn get_star_activity<R>(
+    requester: &R,
+    channel: &ChannelId,
+    comments_per_post: u32,
+) -> Result<Vec<StarActivity>>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let grouped_boards = requester.get_channel_grouped_boards(channel).await?;
+    let mut activity = Vec::new();
+
+    for board in grouped_boards
+        .iter()
+        .flat_map(|g| g.boards.iter())
+        .filter(|b| !b.is_paid())
+    {
+        let posts = requester
+            .get_board_posts(channel, board.board_id as u64)
+            .await?;
+
+        for mut post in posts.data {
+            let reactions = std::mem::take(&mut post.star_reactions);
+            activity.extend(reactions.into_iter().map(|mut reaction| {
+                reaction.created_at.get_or_insert(post.created_at);
+
+                StarActivity::Reaction {
+                    post_id: post.post_id.clone(),
+                    reaction,
+                }
+            }));
+
+            if post.comment_count > 0 {
+                let comments = requester
+                    .get_star_comments(&post.post_id, comments_per_post, None)
+                    .await?;
+
+                activity.extend(
+                    comments
+                        .data
+                        .into_iter()
+                        .filter(|c| c.author.is_star())
+                        .map(|comment| StarActivity::Comment {
+                            post_id: post.post_id.clone(),
+                            comment: Box::new(comment),
+                        }),
+                );
+            }
+
+            if post.author.is_star() {
+                activity.push(StarActivity::Post(Box::new(post)));
+            }
+        }
+    }
+
+    activity.sort_by_key(|a| std::cmp::Reverse(a.created_at()));
+
+    Ok(activity)
+}
@@ -0,0 +1,145 @@
+//! Aggregate statistics over a channel's fetched videos.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::channel::{Channel, ChannelVideoList, VideoListItem};
+
+/// Statistics of a channel's videos. Upcoming videos are not counted since
+/// they don't have any plays yet.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelStats {
+    /// Number of videos these stats were computed from
+    pub video_count: usize,
+    /// Total number of videos the channel has, may be more than
+    /// `video_count` if not every page was fetched
+    pub total_video_count: Option<u32>,
+    pub live_count: usize,
+    pub vod_count: usize,
+    pub paid_count: usize,
+    pub total_plays: u64,
+    pub total_likes: u64,
+    pub total_comments: u64,
+    /// Total duration of all videos in seconds
+    pub total_play_time: u64,
+    /// Longest video duration in seconds
+    pub longest_play_time: u32,
+    pub first_upload_at: Option<DateTime<Utc>>,
+    pub last_upload_at: Option<DateTime<Utc>>,
+    /// Counters from `Channel`, only set with `with_channel`
+    pub member_count: Option<i64>,
+    pub post_count_of_star: Option<i64>,
+    pub video_count_of_star: Option<i64>,
+    pub video_play_count_of_star: Option<i64>,
+    pub video_like_count_of_star: Option<i64>,
+    pub video_comment_count_of_star: Option<i64>,
+}
+
+impl ChannelStats {
+    pub fn from_videos<'a, I>(videos: I) -> Self
+    where
+        I: IntoIterator<Item = &'a VideoListItem>,
+    {
+        let mut stats = Self::default();
+
+        for video in videos.into_iter().filter(|v| !v.upcoming_yn) {
+            stats.video_count += 1;
+
+            if video.is_live() {
+                stats.live_count += 1;
+            } else {
+                stats.vod_count += 1;
+            }
+
+            if video.is_paid() {
+                stats.paid_count += 1;
+            }
+
+            stats.total_plays += u64::from(video.play_count);
+            stats.total_likes += u64::from(video.like_count);
+            stats.total_comments += u64::from(video.comment_count);
+            stats.total_play_time += u64::from(video.play_time);
+            stats.longest_play_time = stats.longest_play_time.max(video.play_time);
+
+            let at = video.on_air_start_at;
+            stats.first_upload_at = Some(stats.first_upload_at.map_or(at, |t| t.min(at)));
+            stats.last_upload_at = Some(stats.last_upload_at.map_or(at, |t| t.max(at)));
+        }
+
+        stats
+    }
+
+    /// Stats of a page of a channel's video list, or of multiple pages with
+    /// their videos combined into one list
+    pub fn from_video_list(list: &ChannelVideoList) -> Self {
+        Self {
+            total_video_count: Some(list.total_video_count),
+            ..Self::from_videos(&list.video_list)
+        }
+    }
+
+    /// Adds the member count and star counters of the channel
+    pub fn with_channel(mut self, channel: &Channel) -> Self {
+        self.member_count = channel.member_count;
+        self.post_count_of_star = channel.post_count_of_star;
+        self.video_count_of_star = channel.video_count_of_star;
+        self.video_play_count_of_star = channel.video_play_count_of_star;
+        self.video_like_count_of_star = channel.video_like_count_of_star;
+        self.video_comment_count_of_star = channel.video_comment_count_of_star;
+        self
+    }
+
+    pub fn average_plays(&self) -> f64 {
+        self.average(self.total_plays)
+    }
+
+    pub fn average_likes(&self) -> f64 {
+        self.average(self.total_likes)
+    }
+
+    pub fn average_comments(&self) -> f64 {
+        self.average(self.total_comments)
+    }
+
+    /// Average video duration in seconds
+    pub fn average_play_time(&self) -> f64 {
+        self.average(self.total_play_time)
+    }
+
+    /// Fraction of videos that are live broadcasts, from 0 to 1
+    pub fn live_ratio(&self) -> f64 {
+        self.average(self.live_count as u64)
+    }
+
+    /// Average number of uploads per week between the first and last upload,
+    /// None if there are fewer than two videos
+    pub fn uploads_per_week(&self) -> Option<f64> {
+        let span = self.last_upload_at? - self.first_upload_at?;
+        let weeks = span.num_seconds() as f64 / (7.0 * 24.0 * 60.0 * 60.0);
+
+        if self.video_count < 2 || weeks <= 0.0 {
+            return None;
+        }
+
+        // The first upload starts the span, so only the uploads after it count
+        Some((self.video_count - 1) as f64 / weeks)
+    }
+
+    fn average(&self, total: u64) -> f64 {
+        if self.video_count == 0 {
+            return 0.0;
+        }
+
+        total as f64 / self.video_count as f64
+    }
+}
+
+/// The `n` longest videos by `play_time`, longest first
+pub fn longest_videos(videos: &[VideoListItem], n: usize) -> Vec<&VideoListItem> {
+    let mut sorted: Vec<_> = videos.iter().filter(|v| !v.upcoming_yn).collect();
+    sorted.sort_by_key(|v| std::cmp::Reverse(v.play_time));
+    sorted.truncate(n);
+
+    sorted
+}
@@ -0,0 +1,390 @@
+//! Incremental sync of a channel's videos and posts.
+//!
+//! `SyncState` stores what was last seen in a channel, and each call to
+//! `sync_channel` compares it against the channel's current videos and posts,
+//! reporting what was added, updated or removed since the previous run.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::model::{board_posts::Post, channel::VideoListItem, grouped_board::Board};
+use crate::{ChannelId, VLiveRequester};
+
+/// Last seen state of a channel
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncState {
+    pub channel_code: String,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    pub videos: HashMap<u32, VideoSnapshot>,
+    pub boards: HashMap<i64, BoardSnapshot>,
+}
+
+impl SyncState {
+    pub fn new(channel_code: &str) -> Self {
+        Self {
+            channel_code: channel_code.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Loads a state saved with `save`, None if the file doesn't exist
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<Self>> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let state = serde_json::from_slice(&fs::read(path)?)?;
+
+        Ok(Some(state))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    /// Compares every video currently in the channel against the state,
+    /// adding what changed to `report`. Videos missing from `videos` are
+    /// treated as removed.
+    pub fn update_videos<I>(&mut self, videos: I, report: &mut SyncReport)
+    where
+        I: IntoIterator<Item = VideoListItem>,
+    {
+        let mut seen = HashSet::new();
+
+        for video in videos {
+            if !seen.insert(video.video_seq) {
+                continue;
+            }
+
+            let snapshot = VideoSnapshot::from(&video);
+
+            match self.videos.insert(video.video_seq, snapshot.clone()) {
+                None => report.new_videos.push(video),
+                Some(previous) if previous != snapshot => {
+                    report.updated_videos.push(VideoChange { previous, video })
+                }
+                Some(_) => {}
+            }
+        }
+
+        self.videos.retain(|seq, _| {
+            let keep = seen.contains(seq);
+
+            if !keep {
+                report.removed_videos.push(*seq);
+            }
+
+            keep
+        });
+    }
+
+    /// Compares every post currently in a board against the state, adding
+    /// what changed to `report`. Posts missing from `posts` are treated as
+    /// removed.
+    pub fn update_board<I>(&mut self, board: &Board, posts: I, report: &mut SyncReport)
+    where
+        I: IntoIterator<Item = Post>,
+    {
+        let snapshot = self
+            .boards
+            .entry(board.board_id)
+            .or_insert_with(|| BoardSnapshot {
+                title: board.title.clone(),
+                last_updated_at: board.last_updated_at,
+                posts: HashMap::new(),
+            });
+
+        snapshot.title = board.title.clone();
+        snapshot.last_updated_at = board.last_updated_at;
+
+        let mut seen = HashSet::new();
+
+        for post in posts {
+            if !seen.insert(post.post_id.clone()) {
+                continue;
+            }
+
+            let post_snapshot = PostSnapshot::from(&post);
+
+            match snapshot
+                .posts
+                .insert(post.post_id.clone(), post_snapshot.clone())
+            {
+                None => report.new_posts.push(post),
+                Some(previous) if previous != post_snapshot => {
+                    report.updated_posts.push(PostChange { previous, post })
+                }
+                Some(_) => {}
+            }
+        }
+
+        snapshot.posts.retain(|post_id, _| {
+            let keep = seen.contains(post_id);
+
+            if !keep {
+                report.removed_posts.push(post_id.clone());
+            }
+
+            keep
+        });
+    }
+
+    /// Removes boards that aren't in `board_ids`, reporting their posts as
+    /// removed
+    fn retain_boards(&mut self, board_ids: &HashSet<i64>, report: &mut SyncReport) {
+        self.boards.retain(|board_id, board| {
+            let keep = board_ids.contains(board_id);
+
+            if !keep {
+                report.removed_posts.extend(board.posts.keys().cloned());
+            }
+
+            keep
+        });
+    }
+}
+
+/// Counts of a video when it was last seen
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSnapshot {
+    pub title: String,
+    pub play_count: u32,
+    pub like_count: u32,
+    pub comment_count: u32,
+}
+
+impl From<&VideoListItem> for VideoSnapshot {
+    fn from(video: &VideoListItem) -> Self {
+        Self {
+            title: video.title.clone(),
+            play_count: video.play_count,
+            like_count: video.like_count,
+            comment_count: video.comment_count,
+        }
+    }
+}
+
+/// Board and its posts when it was last seen
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardSnapshot {
+    pub title: String,
+    pub last_updated_at: DateTime<Utc>,
+    pub posts: HashMap<String, PostSnapshot>,
+}
+
+/// Counts of a post when it was last seen
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PostSnapshot {
+    pub title: String,
+    pub post_version: String,
+    pub comment_count: i64,
+    pub emotion_count: i64,
+}
+
+impl From<&Post> for PostSnapshot {
+    fn from(post: &Post) -> Self {
+        Self {
+            title: post.title.clone(),
+            post_version: post.post_version.clone(),
+            comment_count: post.comment_count,
+            emotion_count: post.emotion_count,
+        }
+    }
+}
+
+/// Video whose title or counts changed since the last sync
+#[derive(Debug, Clone)]
+pub struct VideoChange {
+    pub previous: VideoSnapshot,
+    pub video: VideoListItem,
+}
+
+/// Post whose title, version or counts changed since the last sync
+#[derive(Debug, Clone)]
+pub struct PostChange {
+    pub previous: PostSnapshot,
+    pub post: Post,
+}
+
+/// Changes found by `sync_channel`
+#[derive(Debug, Default, Clone)]
+pub struct SyncReport {
+    pub new_videos: Vec<VideoListItem>,
+    pub updated_videos: Vec<VideoChange>,
+    /// Seqs of videos no longer in the channel's video list
+    pub removed_videos: Vec<u32>,
+    pub new_posts: Vec<Post>,
+    pub updated_posts: Vec<PostChange>,
+    /// IDs of posts no longer in their board, or in a board that was removed
+    pub removed_posts: Vec<String>,
+    /// Boards skipped since they haven't been updated since the last sync
+    pub skipped_boards: Vec<i64>,
+}
+
+impl SyncReport {
+    /// Checks if nothing changed since the last sync
+    pub fn is_empty(&self) -> bool {
+        self.new_videos.is_empty()
+            && self.updated_videos.is_empty()
+            && self.removed_videos.is_empty()
+            && self.new_posts.is_empty()
+            && self.updated_posts.is_empty()
+            && self.removed_posts.is_empty()
+    }
+}
+
+/// Compares a channel's current videos and posts against `state`, updating
+/// `state` and returning what changed. If any request fails, `state` is left
+/// as it was.
+///
+/// Every page of the video list is fetched on each run. Boards whose
+/// `last_updated_at` hasn't changed since the last sync are skipped, so count
+/// changes on posts in those boards are only picked up once the board is
+/// updated again. Boards that require payment are skipped.
+pub async fn sync_channel<R>(
+    requester: &R,
+    channel: &ChannelId,
+    state: &mut SyncState,
+    page_size: u32,
+) -> Result<SyncReport>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let channel_code = channel.code(requester).await?;
+
+    if !state.channel_code.is_empty() && state.channel_code != channel_code {
+        return Err(Error::Vlive(format!(
+            "Sync state belongs to channel {}, not {}",
+            state.channel_code, channel_code
+        )));
+    }
+
+    // Changes are made to a copy and only kept once every request succeeded
+    let mut next = state.clone();
+    next.channel_code = channel_code.to_string();
+
+    let mut report = SyncReport::default();
+
+    let videos = fetch_all_videos(requester, channel, page_size).await?;
+    next.update_videos(videos, &mut report);
+    sync_boards(requester, channel, &mut next, page_size, &mut report).await?;
+
+    next.last_synced_at = Some(Utc::now());
+    *state = next;
+
+    Ok(report)
+}
+
+async fn fetch_all_videos<R>(
+    requester: &R,
+    channel: &ChannelId,
+    page_size: u32,
+) -> Result<Vec<VideoListItem>>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let mut videos = Vec::new();
+    let mut seen = HashSet::new();
+    let mut page_no = 1;
+
+    loop {
+        let list = requester
+            .get_channel_video_list(channel, page_size, page_no)
+            .await?;
+
+        if list.video_list.is_empty() {
+            break;
+        }
+
+        // Videos can shift onto the next page while paging
+        for video in list.video_list {
+            if seen.insert(video.video_seq) {
+                videos.push(video);
+            }
+        }
+
+        if seen.len() >= list.total_video_count as usize {
+            break;
+        }
+
+        page_no += 1;
+    }
+
+    Ok(videos)
+}
+
+async fn sync_boards<R>(
+    requester: &R,
+    channel: &ChannelId,
+    state: &mut SyncState,
+    page_size: u32,
+    report: &mut SyncReport,
+) -> Result<()>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let grouped_boards = requester.get_channel_grouped_boards(channel).await?;
+    let boards: Vec<&Board> = grouped_boards
+        .iter()
+        .flat_map(|g| g.boards.iter())
+        .filter(|b| !b.is_paid())
+        .collect();
+
+    let current: HashSet<i64> = boards.iter().map(|b| b.board_id).collect();
+    state.retain_boards(&current, report);
+
+    for board in boards {
+        if let Some(snapshot) = state.boards.get(&board.board_id) {
+            if snapshot.last_updated_at >= board.last_updated_at {
+                report.skipped_boards.push(board.board_id);
+                continue;
+            }
+        }
+
+        let posts = fetch_all_posts(requester, channel, board.board_id as u64, page_size).await?;
+        state.update_board(board, posts, report);
+    }
+
+    Ok(())
+}
+
+async fn fetch_all_posts<R>(
+    requester: &R,
+    channel: &ChannelId,
+    board_id: u64,
+    page_size: u32,
+) -> Result<Vec<Post>>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let mut posts = Vec::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let page = requester
+            .get_board_posts_page(channel, board_id, page_size, after.as_deref())
+            .await?;
+
+        after = page.next_after().map(|s| s.to_string());
+        let empty = page.data.is_empty();
+        posts.extend(page.data);
+
+        if after.is_none() || empty {
+            break;
+        }
+    }
+
+    Ok(posts)
+}
@@ -0,0 +1,145 @@
+//! Timestamp helpers
+//!
+//! All timestamps in models are `DateTime<Utc>`. VLive itself shows times in
+//! KST, so use `ToKst` to convert them for display.
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Korea Standard Time (UTC+9), the timezone used on VLive
+pub fn kst() -> FixedOffset {
+    FixedOffset::east(9 * 60 * 60)
+}
+
+/// Converts a timestamp to KST
+pub trait ToKst {
+    fn to_kst(&self) -> DateTime<FixedOffset>;
+}
+
+impl<Tz: TimeZone> ToKst for DateTime<Tz> {
+    fn to_kst(&self) -> DateTime<FixedOffset> {
+        self.with_timezone(&kst())
+    }
+}
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// `amount` units of `unit_secs` seconds before `now`, None if it overflows
+fn ago(now: DateTime<Utc>, unit_secs: i64, amount: i64) -> Option<DateTime<Utc>> {
+    let millis = unit_secs.checked_mul(amount)?.checked_mul(1000)?;
+
+    now.checked_sub_signed(Duration::milliseconds(millis))
+}
+
+/// Parses a relative or absolute "posted age" shown on VLive, such as
+/// `3 hours ago`, `Jan 2, 2021`, `3시간 전` or `2021.01.02.`, into an
+/// approximate timestamp relative to `now`.
+///
+/// Dates without a time are treated as midnight KST. Months and years in
+/// relative ages are approximated as 30 and 365 days.
+pub fn parse_posted_age(age: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    lazy_static! {
+        static ref EN_RE: Regex = Regex::new(
+            r"(?i)^(\d+|an?|one)\s+(second|sec|minute|min|hour|hr|day|week|month|year)s?\s+ago$"
+        )
+        .unwrap();
+        static ref KO_RE: Regex =
+            Regex::new(r"^(\d+)\s*(초|분|시간|일|주|개월|달|년)\s*전$").unwrap();
+        static ref KO_MONTH_DAY_RE: Regex = Regex::new(r"^(\d{1,2})월\s*(\d{1,2})일$").unwrap();
+    }
+
+    let age = age.trim();
+
+    if age.eq_ignore_ascii_case("just now") || age.starts_with("방금") {
+        return Some(now);
+    }
+
+    if let Some(caps) = EN_RE.captures(age) {
+        let amount = match caps[1].to_lowercase().as_str() {
+            "a" | "an" | "one" => 1,
+            n => n.parse().ok()?,
+        };
+
+        let unit = match caps[2].to_lowercase().as_str() {
+            "second" | "sec" => 1,
+            "minute" | "min" => MINUTE,
+            "hour" | "hr" => HOUR,
+            "day" => DAY,
+            "week" => 7 * DAY,
+            "month" => 30 * DAY,
+            _ => 365 * DAY,
+        };
+
+        return ago(now, unit, amount);
+    }
+
+    if let Some(caps) = KO_RE.captures(age) {
+        let amount = caps[1].parse().ok()?;
+
+        let unit = match &caps[2] {
+            "초" => 1,
+            "분" => MINUTE,
+            "시간" => HOUR,
+            "일" => DAY,
+            "주" => 7 * DAY,
+            "개월" | "달" => 30 * DAY,
+            _ => 365 * DAY,
+        };
+
+        return ago(now, unit, amount);
+    }
+
+    // Absolute dates with a year
+    let formats = [
+        "%b %d, %Y",
+        "%B %d, %Y",
+        "%Y.%m.%d.",
+        "%Y.%m.%d",
+        "%Y. %m. %d.",
+        "%Y-%m-%d",
+    ];
+
+    if let Some(date) = formats
+        .iter()
+        .find_map(|f| NaiveDate::parse_from_str(age, f).ok())
+    {
+        return kst_midnight(date);
+    }
+
+    // Dates without a year are in the current year, or the previous year if
+    // that would be in the future
+    let today = now.to_kst().naive_local().date();
+    let month_day = if let Some(caps) = KO_MONTH_DAY_RE.captures(age) {
+        Some((caps[1].parse().ok()?, caps[2].parse().ok()?))
+    } else {
+        ["%b %d", "%B %d"]
+            .iter()
+            .find_map(|f| {
+                NaiveDate::parse_from_str(
+                    &format!("{} {}", age, today.year()),
+                    &format!("{} %Y", f),
+                )
+                .ok()
+            })
+            .map(|d| (d.month(), d.day()))
+    };
+
+    let (month, day) = month_day?;
+    let date = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+
+    if date > today {
+        kst_midnight(NaiveDate::from_ymd_opt(today.year() - 1, month, day)?)
+    } else {
+        kst_midnight(date)
+    }
+}
+
+fn kst_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    kst()
+        .from_local_datetime(&date.and_hms(0, 0, 0))
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+}
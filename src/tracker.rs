@@ -0,0 +1,204 @@
+//! Time series of video play, like and comment counts.
+//!
+//! `Tracker` doesn't schedule anything itself. Call `Tracker::sample`
+//! periodically, such as from a `tokio::time::interval` loop, to record the
+//! current counts of each tracked video.
+
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::error::{Error, Result};
+use crate::model::{channel::ChannelVideoList, channel::VideoListItem, video::OfficialVideo};
+use crate::VLiveRequester;
+
+/// Counts of a video at a point in time
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CountSample {
+    pub sampled_at: DateTime<Utc>,
+    pub play_count: i64,
+    pub like_count: i64,
+    pub comment_count: i64,
+}
+
+impl CountSample {
+    pub fn from_video(video: &OfficialVideo, sampled_at: DateTime<Utc>) -> Self {
+        Self {
+            sampled_at,
+            play_count: video.play_count,
+            like_count: video.like_count,
+            comment_count: video.comment_count,
+        }
+    }
+
+    pub fn from_list_item(video: &VideoListItem, sampled_at: DateTime<Utc>) -> Self {
+        Self {
+            sampled_at,
+            play_count: video.play_count.into(),
+            like_count: video.like_count.into(),
+            comment_count: video.comment_count.into(),
+        }
+    }
+}
+
+/// Change in counts per hour between two samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrowthRate {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub plays_per_hour: f64,
+    pub likes_per_hour: f64,
+    pub comments_per_hour: f64,
+}
+
+impl GrowthRate {
+    /// Growth from `a` to `b`, None if they were sampled at the same time or
+    /// `b` is before `a`
+    pub fn between(a: &CountSample, b: &CountSample) -> Option<Self> {
+        let hours = (b.sampled_at - a.sampled_at).num_milliseconds() as f64 / 3_600_000.0;
+
+        if hours <= 0.0 {
+            return None;
+        }
+
+        Some(Self {
+            from: a.sampled_at,
+            to: b.sampled_at,
+            plays_per_hour: (b.play_count - a.play_count) as f64 / hours,
+            likes_per_hour: (b.like_count - a.like_count) as f64 / hours,
+            comments_per_hour: (b.comment_count - a.comment_count) as f64 / hours,
+        })
+    }
+}
+
+/// Samples of a single video, oldest first
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct VideoSeries {
+    pub samples: Vec<CountSample>,
+}
+
+impl VideoSeries {
+    /// Adds a sample, keeping samples sorted by time
+    pub fn push(&mut self, sample: CountSample) {
+        let pos = self
+            .samples
+            .partition_point(|s| s.sampled_at <= sample.sampled_at);
+        self.samples.insert(pos, sample);
+    }
+
+    pub fn latest(&self) -> Option<&CountSample> {
+        self.samples.last()
+    }
+
+    /// Growth between each pair of consecutive samples
+    pub fn rates(&self) -> Vec<GrowthRate> {
+        self.samples
+            .windows(2)
+            .filter_map(|w| GrowthRate::between(&w[0], &w[1]))
+            .collect()
+    }
+
+    /// Average growth from the first to the latest sample
+    pub fn overall(&self) -> Option<GrowthRate> {
+        GrowthRate::between(self.samples.first()?, self.samples.last()?)
+    }
+
+    /// Average growth from the first sample at or after `start`, such as the
+    /// end of a broadcast, to the latest sample
+    pub fn since(&self, start: DateTime<Utc>) -> Option<GrowthRate> {
+        let first = self.samples.iter().find(|s| s.sampled_at >= start)?;
+
+        GrowthRate::between(first, self.samples.last()?)
+    }
+
+    /// Average growth over the most recent `window` of samples
+    pub fn recent(&self, window: Duration) -> Option<GrowthRate> {
+        self.since(self.latest()?.sampled_at - window)
+    }
+}
+
+/// Tracks count time series of chosen videos, by video seq
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Tracker {
+    pub videos: BTreeMap<u64, VideoSeries>,
+}
+
+impl Tracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking a video, keeping existing samples if already tracked
+    pub fn track(&mut self, video_seq: u64) {
+        self.videos.entry(video_seq).or_default();
+    }
+
+    /// Stops tracking a video, returning its samples
+    pub fn untrack(&mut self, video_seq: u64) -> Option<VideoSeries> {
+        self.videos.remove(&video_seq)
+    }
+
+    pub fn series(&self, video_seq: u64) -> Option<&VideoSeries> {
+        self.videos.get(&video_seq)
+    }
+
+    /// Records a sample for a tracked video, ignored if the video isn't tracked
+    pub fn record(&mut self, video_seq: u64, sample: CountSample) {
+        if let Some(series) = self.videos.get_mut(&video_seq) {
+            series.push(sample);
+        }
+    }
+
+    /// Records samples for tracked videos in a page of a channel's video
+    /// list. This only needs a single request for many videos, but the counts
+    /// in video lists may lag behind `get_video`.
+    pub fn record_list(&mut self, list: &ChannelVideoList, sampled_at: DateTime<Utc>) {
+        for video in &list.video_list {
+            self.record(
+                video.video_seq.into(),
+                CountSample::from_list_item(video, sampled_at),
+            );
+        }
+    }
+
+    /// Fetches and records the current counts of every tracked video, with at
+    /// most `concurrency` requests at a time. Returns the videos that failed
+    /// to be sampled, the rest are still recorded.
+    pub async fn sample<R>(&mut self, requester: &R, concurrency: usize) -> Vec<(u64, Error)>
+    where
+        R: VLiveRequester + Sync + ?Sized,
+    {
+        let video_seqs: Vec<u64> = self.videos.keys().copied().collect();
+        let results: Vec<(u64, Result<CountSample>)> = stream::iter(video_seqs)
+            .map(|video_seq| async move { (video_seq, fetch_sample(requester, video_seq).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut failed = Vec::new();
+
+        for (video_seq, result) in results {
+            match result {
+                Ok(sample) => self.record(video_seq, sample),
+                Err(e) => failed.push((video_seq, e)),
+            }
+        }
+
+        failed
+    }
+}
+
+async fn fetch_sample<R>(requester: &R, video_seq: u64) -> Result<CountSample>
+where
+    R: VLiveRequester + Sync + ?Sized,
+{
+    let state = requester.get_video(video_seq).await?;
+    let detail = state
+        .post_detail
+        .get_detail()
+        .ok_or(Error::MissingDetails)?;
+
+    Ok(CountSample::from_video(&detail.official_video, Utc::now()))
+}
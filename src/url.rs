@@ -0,0 +1,141 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::model::{board_posts::Post, channel::Channel, grouped_board::Board, video::VideoState};
+use crate::{ChannelId, VLiveRequester};
+
+/// A parsed VLive URL
+#[derive(Debug, Clone, PartialEq)]
+pub enum VLiveUrl {
+    /// `https://www.vlive.tv/video/123456`
+    Video { video_seq: u64 },
+    /// `https://www.vlive.tv/channel/FE619` or the legacy
+    /// `http://channels.vlive.tv/FE619`
+    Channel { channel_code: String },
+    /// `https://www.vlive.tv/channel/FE619/board/21`
+    Board { channel_code: String, board_id: u64 },
+    /// `https://www.vlive.tv/post/0-12345`
+    Post { post_id: String },
+}
+
+/// Data fetched for a parsed URL
+#[derive(Debug)]
+pub enum UrlContent {
+    Video(Box<VideoState>),
+    Channel(Box<Channel>),
+    Board(Board),
+    Post(Box<Post>),
+}
+
+impl VLiveUrl {
+    /// Parses any VLive URL, returns an error if the URL isn't recognized
+    pub fn parse(url: &str) -> Result<Self> {
+        lazy_static! {
+            static ref VIDEO_RE: Regex =
+                Regex::new(r"^(?:https?://)?(?:www\.|m\.)?vlive\.tv/video/(\d+)(?:[/?#]|$)").unwrap();
+            static ref BOARD_RE: Regex = Regex::new(
+                r"^(?:https?://)?(?:www\.|m\.)?vlive\.tv/channel/([0-9A-Za-z]+)/board/(\d+)(?:[/?#]|$)"
+            )
+            .unwrap();
+            static ref CHANNEL_RE: Regex = Regex::new(
+                r"^(?:https?://)?(?:(?:www\.|m\.)?vlive\.tv/channel/|channels\.vlive\.tv/)([0-9A-Za-z]+)(?:[/?#]|$)"
+            )
+            .unwrap();
+            static ref POST_RE: Regex =
+                Regex::new(r"^(?:https?://)?(?:www\.|m\.)?vlive\.tv/post/(\d+-\d+)(?:[/?#]|$)").unwrap();
+        }
+
+        let url = url.trim();
+
+        if let Some(caps) = VIDEO_RE.captures(url) {
+            return Ok(Self::Video {
+                video_seq: parse_id(&caps[1])?,
+            });
+        }
+
+        if let Some(caps) = BOARD_RE.captures(url) {
+            return Ok(Self::Board {
+                channel_code: caps[1].to_string(),
+                board_id: parse_id(&caps[2])?,
+            });
+        }
+
+        if let Some(caps) = CHANNEL_RE.captures(url) {
+            return Ok(Self::Channel {
+                channel_code: caps[1].to_string(),
+            });
+        }
+
+        if let Some(caps) = POST_RE.captures(url) {
+            return Ok(Self::Post {
+                post_id: caps[1].to_string(),
+            });
+        }
+
+        Err(Error::Vlive(format!("Unrecognized VLive URL: {}", url)))
+    }
+
+    /// Fetches the video, channel, board or post this URL points to
+    pub async fn fetch<R>(&self, requester: &R) -> Result<UrlContent>
+    where
+        R: VLiveRequester + Sync + ?Sized,
+    {
+        match self {
+            Self::Video { video_seq } => requester
+                .get_video(*video_seq)
+                .await
+                .map(|v| UrlContent::Video(Box::new(v))),
+            Self::Channel { channel_code } => requester
+                .get_channel_info(&ChannelId::from_code(channel_code.as_str()))
+                .await
+                .map(|c| UrlContent::Channel(Box::new(c))),
+            Self::Board {
+                channel_code,
+                board_id,
+            } => requester
+                .get_channel_board(&ChannelId::from_code(channel_code.as_str()), *board_id)
+                .await
+                .map(UrlContent::Board),
+            Self::Post { post_id } => requester
+                .get_post(post_id)
+                .await
+                .map(|p| UrlContent::Post(Box::new(p))),
+        }
+    }
+}
+
+impl FromStr for VLiveUrl {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl Display for VLiveUrl {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Video { video_seq } => write!(f, "https://www.vlive.tv/video/{}", video_seq),
+            Self::Channel { channel_code } => {
+                write!(f, "https://www.vlive.tv/channel/{}", channel_code)
+            }
+            Self::Board {
+                channel_code,
+                board_id,
+            } => write!(
+                f,
+                "https://www.vlive.tv/channel/{}/board/{}",
+                channel_code, board_id
+            ),
+            Self::Post { post_id } => write!(f, "https://www.vlive.tv/post/{}", post_id),
+        }
+    }
+}
+
+fn parse_id(s: &str) -> Result<u64> {
+    s.parse()
+        .map_err(|_| Error::Vlive(format!("Invalid ID in URL: {}", s)))
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use common::{board, post, video_list_item, MockRequester};
+use reqwest::Client;
+use std::path::PathBuf;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use vlive::archive::{archive_channel, ArchiveOptions, Manifest};
+use vlive::ChannelId;
+
+fn archive_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("vlive-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    dir
+}
+
+fn options() -> ArchiveOptions {
+    ArchiveOptions {
+        thumbnails: false,
+        captions: false,
+        photos: false,
+        media: false,
+        page_size: 2,
+        skip_paid_boards: true,
+    }
+}
+
+#[tokio::test]
+async fn test_archive_resume() {
+    let dir = archive_dir("archive-resume");
+    let mut paid_board = board(22);
+    paid_board.pay_required = true;
+
+    let mut mock = MockRequester {
+        videos: (1..=3).map(video_list_item).collect(),
+        boards: vec![board(21), paid_board],
+        fail_on: Some("posts 21 after=2".to_string()),
+        ..Default::default()
+    };
+    mock.posts
+        .insert(21, (1..=5).map(|i| post(&format!("0-{}", i))).collect());
+
+    let channel = ChannelId::from_code("FE619");
+    let client = Client::new();
+
+    assert!(archive_channel(&mock, &client, &channel, &dir, &options())
+        .await
+        .is_err());
+
+    let manifest = Manifest::load(&dir).await.unwrap().unwrap();
+    assert!(manifest.videos_complete);
+    assert_eq!(manifest.videos.len(), 3);
+    assert_eq!(manifest.boards.len(), 1);
+    assert_eq!(manifest.boards[0].after.as_deref(), Some("2"));
+    assert_eq!(manifest.boards[0].posts.len(), 2);
+    assert!(!manifest.is_complete());
+    assert!(!dir.join("index.json").exists());
+
+    // Resumes from the saved cursor without fetching videos again
+    mock.fail_on = None;
+    mock.take_requests();
+
+    let index = archive_channel(&mock, &client, &channel, &dir, &options())
+        .await
+        .unwrap();
+
+    assert_eq!(index.videos.len(), 3);
+    assert_eq!(index.boards.len(), 1);
+    assert_eq!(index.boards[0].posts.len(), 5);
+    assert!(index.boards[0].complete);
+    assert_eq!(
+        mock.take_requests(),
+        vec![
+            "channel FE619",
+            "boards FE619",
+            "posts 21 after=2",
+            "posts 21 after=4",
+        ]
+    );
+    assert!(dir.join("index.json").exists());
+    assert!(dir.join("videos/3/video.json").exists());
+    assert!(dir.join("boards/21/posts/0-5.json").exists());
+    assert!(Manifest::load(&dir).await.unwrap().unwrap().is_complete());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_archive_other_channel() {
+    let dir = archive_dir("archive-other-channel");
+    let mock = MockRequester::default();
+    let client = Client::new();
+
+    archive_channel(&mock, &client, &"FE619".into(), &dir, &options())
+        .await
+        .unwrap();
+
+    assert!(
+        archive_channel(&mock, &client, &"EDBF".into(), &dir, &options())
+            .await
+            .is_err()
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Serves `/thumb.jpg` and responds 404 to anything else, returns the address
+async fn file_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = [0; 1024];
+            let n = socket.read(&mut buf).await.unwrap_or(0);
+            let response = if buf[..n].starts_with(b"GET /thumb.jpg ") {
+                "HTTP/1.1 200 OK\r\nContent-Length: 3\r\nConnection: close\r\n\r\njpg"
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            };
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_archive_missing_thumbnail() {
+    let dir = archive_dir("archive-missing-thumbnail");
+    let server = file_server().await;
+
+    let mut found = video_list_item(1);
+    found.thumbnail = format!("{}/thumb.jpg", server);
+    let mut missing = video_list_item(2);
+    missing.thumbnail = format!("{}/missing.jpg", server);
+
+    let mock = MockRequester {
+        videos: vec![found, missing.clone()],
+        ..Default::default()
+    };
+    let options = ArchiveOptions {
+        thumbnails: true,
+        ..options()
+    };
+
+    let index = archive_channel(&mock, &Client::new(), &"FE619".into(), &dir, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(index.videos.len(), 2);
+    assert!(index.videos[0].failed_downloads.is_empty());
+    assert_eq!(index.videos[1].failed_downloads, vec![missing.thumbnail]);
+    assert_eq!(
+        std::fs::read(dir.join("videos/1/thumbnail.jpg")).unwrap(),
+        b"jpg"
+    );
+    assert!(!dir.join("videos/2/thumbnail.jpg").exists());
+    assert!(!dir.join("videos/2/thumbnail.jpg.part").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
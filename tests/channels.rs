@@ -1,11 +1,11 @@
 use reqwest::Client;
-use vlive::VLiveRequester;
+use vlive::{ChannelId, VLiveRequester};
 
 #[tokio::test]
 async fn test_search_channel() {
     let client = Client::new();
 
-    let channels = client.search_channel("bts".into(), 10).await.unwrap();
+    let channels = client.search_channel("bts", 10).await.unwrap();
     let channel = channels
         .0
         .iter()
@@ -26,9 +26,9 @@ async fn test_get_channel_info() {
 
     for channel_code in channels {
         let channel = client
-            .get_channel_info(channel_code.into())
+            .get_channel_info(&channel_code.into())
             .await
-            .expect(&format!("get_channel_info {}", channel_code));
+            .unwrap_or_else(|e| panic!("get_channel_info {}: {}", channel_code, e));
 
         assert_eq!(channel.channel_code, channel_code);
     }
@@ -38,7 +38,7 @@ async fn test_get_channel_info() {
 async fn test_decode_channel_code() {
     let client = Client::new();
 
-    let code = client.decode_channel_code("FE619".into()).await.unwrap();
+    let code = client.decode_channel_code("FE619").await.unwrap();
 
     assert!(code == 13);
 }
@@ -46,7 +46,10 @@ async fn test_decode_channel_code() {
 #[tokio::test]
 async fn test_get_channel_video_list() {
     let client = Client::new();
-    let channel = client.get_channel_video_list(364, 30, 1).await.unwrap();
+    let channel = client
+        .get_channel_video_list(&364.into(), 30, 1)
+        .await
+        .unwrap();
 
     println!(
         "Found Channel: {}, {} videos",
@@ -62,7 +65,7 @@ async fn test_get_channel_video_list() {
 async fn test_get_grouped_boards() {
     let client = Client::new();
     let grouped_boards = client
-        .get_channel_grouped_boards("EDBF".into())
+        .get_channel_grouped_boards(&"EDBF".into())
         .await
         .unwrap();
 
@@ -73,7 +76,7 @@ async fn test_get_grouped_boards() {
 #[tokio::test]
 async fn test_get_board() {
     let client = Client::new();
-    let board = client.get_channel_board("EDBF".into(), 21).await.unwrap();
+    let board = client.get_channel_board(&"EDBF".into(), 21).await.unwrap();
 
     println!("Found board: {:#?}", board);
     assert_eq!(board.title, "Notice".to_string());
@@ -82,17 +85,50 @@ async fn test_get_board() {
 #[tokio::test]
 async fn test_get_board_posts() {
     let client = Client::new();
-    let posts = client.get_board_posts("EDBF".into(), 21).await.unwrap();
+    let posts = client.get_board_posts(&"EDBF".into(), 21).await.unwrap();
 
     println!("Found board posts: {:#?}", posts);
     assert!(!posts.data.is_empty());
 }
 
+#[tokio::test]
+async fn test_get_board_posts_page() {
+    let client = Client::new();
+    let channel = "EDBF".into();
+    let first = client
+        .get_board_posts_page(&channel, 21, 5, None)
+        .await
+        .unwrap();
+    let after = first.next_after().expect("Missing next page");
+
+    let second = client
+        .get_board_posts_page(&channel, 21, 5, Some(after))
+        .await
+        .unwrap();
+
+    println!("Found second page of board posts: {:#?}", second);
+    assert!(first.data.len() <= 5);
+    assert!(second
+        .data
+        .iter()
+        .all(|p| first.data.iter().all(|f| f.post_id != p.post_id)));
+}
+
+#[tokio::test]
+async fn test_get_post() {
+    let client = Client::new();
+    let post = client.get_post("0-20890974").await.unwrap();
+
+    println!("Found post: {:#?}", post);
+    assert!(post.is_video());
+    assert_eq!(post.url, "https://www.vlive.tv/post/0-20890974");
+}
+
 #[tokio::test]
 async fn test_video_item() {
     let client = Client::new();
     let video_list = client
-        .get_channel_video_list(364, 30, 1)
+        .get_channel_video_list(&364.into(), 30, 1)
         .await
         .unwrap()
         .video_list;
@@ -111,10 +147,81 @@ async fn test_video_item() {
 #[tokio::test]
 async fn test_get_upcoming_video_list() {
     let client = Client::new();
-    let upcoming_videos = client.get_upcoming_video_list(6, 30, 1).await.unwrap();
+    let upcoming_videos = client
+        .get_upcoming_video_list(&6.into(), 30, 1)
+        .await
+        .unwrap();
 
     let video_count = upcoming_videos.video_list.map(|x| x.len()).unwrap_or(0);
 
     println!("Found {} upcoming videos", video_count);
-    assert!(true);
+}
+
+#[tokio::test]
+async fn test_search_channel_videos() {
+    let client = Client::new();
+    let results = client
+        .search_channel_videos(&"FE619".into(), "run bts", 10, None)
+        .await
+        .unwrap();
+
+    println!("Found videos: {:#?}", results.videos().collect::<Vec<_>>());
+    assert!(!results.data.is_empty());
+}
+
+#[tokio::test]
+async fn test_channel_id_resolve() {
+    let client = Client::new();
+    let channel = ChannelId::from_code("FE619");
+
+    assert_eq!(channel.seq(&client).await.unwrap(), 13);
+    assert_eq!(channel.cached_seq(), Some(13));
+
+    let channel = ChannelId::from_seq(13);
+    assert_eq!(channel.code(&client).await.unwrap(), "FE619");
+}
+
+#[tokio::test]
+async fn test_get_star_activity() {
+    let client = Client::new();
+    let activity = vlive::star::get_star_activity(&client, &"EDBF".into(), 10)
+        .await
+        .unwrap();
+
+    println!("Found star activity: {:#?}", activity);
+    assert!(activity.iter().all(|a| a.author().is_star()));
+}
+
+#[tokio::test]
+async fn test_sync_channel() {
+    let client = Client::new();
+    let channel = "EDBF".into();
+    let mut state = vlive::sync::SyncState::default();
+
+    let first = vlive::sync::sync_channel(&client, &channel, &mut state, 100)
+        .await
+        .unwrap();
+    assert!(!first.new_videos.is_empty());
+    assert!(first.skipped_boards.is_empty());
+
+    let second = vlive::sync::sync_channel(&client, &channel, &mut state, 100)
+        .await
+        .unwrap();
+
+    println!("Found changes: {:#?}", second);
+    assert!(second.removed_videos.is_empty());
+    assert!(!second.skipped_boards.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_paid_packages() {
+    let client = Client::new();
+    let packages = vlive::products::get_paid_packages(&client, &"FE619".into())
+        .await
+        .unwrap();
+
+    println!("Found paid packages: {:#?}", packages);
+    assert!(packages
+        .iter()
+        .all(|p| p.videos.iter().all(|v| v.is_paid())));
 }
@@ -0,0 +1,293 @@
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use reqwest::{header::HeaderMap, StatusCode};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use vlive::error::Result;
+use vlive::model::{
+    board_posts::{BoardPosts, NextParams, Paging, Post},
+    channel::{self, ChannelInfo, VideoListItem},
+    comment::Comments,
+    grouped_board::{Board, GroupedBoard, GroupedBoards},
+    member::Member,
+    recent_video::RecentVideo,
+    search::VideoSearchResults,
+    video,
+};
+use vlive::{ChannelId, Error, RawResponse, VLiveRequester};
+
+/// Video list item from `fixtures/video_list_item.json` with the given seq
+pub fn video_list_item(video_seq: u32) -> VideoListItem {
+    let mut video: VideoListItem =
+        serde_json::from_str(include_str!("../fixtures/video_list_item.json")).unwrap();
+    video.video_seq = video_seq;
+    video.title = format!("Video {}", video_seq);
+
+    video
+}
+
+/// Video list item that aired at 20:00 KST (11:00 UTC) on the given day
+pub fn video_list_item_at(video_seq: u32, year: i32, month: u32, day: u32) -> VideoListItem {
+    let mut video = video_list_item(video_seq);
+    video.on_air_start_at = Utc.ymd(year, month, day).and_hms(11, 0, 0);
+    video.will_start_at = video.on_air_start_at;
+
+    video
+}
+
+/// Post from `fixtures/board_post.json` with the given ID
+pub fn post(post_id: &str) -> Post {
+    let mut post: Post = serde_json::from_str(include_str!("../fixtures/board_post.json")).unwrap();
+    post.post_id = post_id.to_string();
+
+    post
+}
+
+/// Board from `fixtures/board.json` with the given ID
+pub fn board(board_id: i64) -> Board {
+    let mut board: Board = serde_json::from_str(include_str!("../fixtures/board.json")).unwrap();
+    board.board_id = board_id;
+
+    board
+}
+
+pub fn channel_info() -> ChannelInfo {
+    serde_json::from_str(include_str!("../fixtures/channel_info.json")).unwrap()
+}
+
+/// Requester that serves a fixed channel without making any requests
+#[derive(Default)]
+pub struct MockRequester {
+    pub videos: Vec<VideoListItem>,
+    pub boards: Vec<Board>,
+    /// Posts of each board, by board ID
+    pub posts: HashMap<i64, Vec<Post>>,
+    /// Request that fails, such as `posts 21 after=2`
+    pub fail_on: Option<String>,
+    /// Requests made so far
+    pub requests: Mutex<Vec<String>>,
+}
+
+/// Wraps a value in a response with an empty body
+fn raw<T>(value: Result<T>) -> Result<RawResponse<T>> {
+    Ok(RawResponse {
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: String::new(),
+        value,
+    })
+}
+
+impl MockRequester {
+    fn request(&self, request: String) -> Result<()> {
+        let failed = self.fail_on.as_deref() == Some(request.as_str());
+        self.requests.lock().unwrap().push(request);
+
+        if failed {
+            return Err(Error::from("Mock request failed"));
+        }
+
+        Ok(())
+    }
+
+    /// Takes the requests made so far
+    pub fn take_requests(&self) -> Vec<String> {
+        std::mem::take(&mut *self.requests.lock().unwrap())
+    }
+}
+
+#[async_trait]
+impl VLiveRequester for MockRequester {
+    async fn search_channel_raw(
+        &self,
+        _query: &str,
+        _num_rows: u64,
+    ) -> Result<RawResponse<channel::ChannelList>> {
+        unimplemented!()
+    }
+
+    async fn get_channel_info_raw(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<RawResponse<channel::Channel>> {
+        self.request(format!("channel {}", channel))?;
+
+        raw(Ok(serde_json::from_str(include_str!(
+            "../fixtures/channel.json"
+        ))
+        .unwrap()))
+    }
+
+    async fn decode_channel_code_raw(&self, channel_code: &str) -> Result<RawResponse<u32>> {
+        self.request(format!("decode {}", channel_code))?;
+
+        raw(Ok(13))
+    }
+
+    async fn get_channel_grouped_boards_raw(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<RawResponse<GroupedBoards>> {
+        self.request(format!("boards {}", channel))?;
+
+        raw(Ok(vec![GroupedBoard {
+            group_title: "Boards".to_string(),
+            boards: self.boards.clone(),
+        }]))
+    }
+
+    async fn get_channel_board_raw(
+        &self,
+        _channel: &ChannelId,
+        _board_id: u64,
+    ) -> Result<RawResponse<Board>> {
+        unimplemented!()
+    }
+
+    async fn get_board_posts_raw(
+        &self,
+        _channel: &ChannelId,
+        _board_id: u64,
+    ) -> Result<RawResponse<BoardPosts>> {
+        unimplemented!()
+    }
+
+    async fn get_board_posts_page_raw(
+        &self,
+        _channel: &ChannelId,
+        board_id: u64,
+        limit: u32,
+        after: Option<&str>,
+    ) -> Result<RawResponse<BoardPosts>> {
+        self.request(format!("posts {} after={}", board_id, after.unwrap_or("")))?;
+
+        let posts = self
+            .posts
+            .get(&(board_id as i64))
+            .cloned()
+            .unwrap_or_default();
+        let start: usize = after.map(|a| a.parse().unwrap()).unwrap_or(0);
+        let end = posts.len().min(start + limit as usize);
+        let next_params = if end < posts.len() {
+            Some(NextParams {
+                limit: limit.to_string(),
+                after: end.to_string(),
+            })
+        } else {
+            None
+        };
+
+        raw(Ok(BoardPosts {
+            paging: Paging { next_params },
+            data: posts[start..end].to_vec(),
+        }))
+    }
+
+    async fn get_post_raw(&self, _post_id: &str) -> Result<RawResponse<Post>> {
+        unimplemented!()
+    }
+
+    async fn get_channel_video_list_raw(
+        &self,
+        channel: &ChannelId,
+        max_rows: u32,
+        page_no: u32,
+    ) -> Result<RawResponse<channel::ChannelVideoList>> {
+        self.request(format!("videos {} page={}", channel, page_no))?;
+
+        let start = (max_rows * (page_no - 1)) as usize;
+
+        raw(Ok(channel::ChannelVideoList {
+            channel_info: channel_info(),
+            total_video_count: self.videos.len() as u32,
+            video_list: self
+                .videos
+                .iter()
+                .skip(start)
+                .take(max_rows as usize)
+                .cloned()
+                .collect(),
+        }))
+    }
+
+    async fn get_upcoming_video_list_raw(
+        &self,
+        _channel: &ChannelId,
+        _max_rows: u32,
+        _page_no: u32,
+    ) -> Result<RawResponse<channel::ChannelUpcomingVideoList>> {
+        unimplemented!()
+    }
+
+    async fn get_current_member_raw(&self) -> Result<RawResponse<Member>> {
+        unimplemented!()
+    }
+
+    async fn get_recent_videos_raw(
+        &self,
+        _page_size: u64,
+        _page_no: u64,
+    ) -> Result<RawResponse<Vec<RecentVideo>>> {
+        unimplemented!()
+    }
+
+    async fn get_video_raw(&self, _video_seq: u64) -> Result<RawResponse<video::VideoState>> {
+        unimplemented!()
+    }
+
+    async fn get_video_streams_raw(&self, video_seq: u64) -> Result<RawResponse<video::Video>> {
+        self.request(format!("streams {}", video_seq))?;
+
+        raw(Err(Error::MissingDetails))
+    }
+
+    async fn search_channel_videos_raw(
+        &self,
+        _channel: &ChannelId,
+        _query: &str,
+        _limit: u32,
+        _after: Option<&str>,
+    ) -> Result<RawResponse<VideoSearchResults>> {
+        unimplemented!()
+    }
+
+    async fn search_channel_posts_raw(
+        &self,
+        _channel: &ChannelId,
+        _query: &str,
+        _limit: u32,
+        _after: Option<&str>,
+    ) -> Result<RawResponse<BoardPosts>> {
+        unimplemented!()
+    }
+
+    async fn get_comments_raw(
+        &self,
+        _post_id: &str,
+        _limit: u32,
+        _after: Option<&str>,
+    ) -> Result<RawResponse<Comments>> {
+        unimplemented!()
+    }
+
+    async fn get_star_comments_raw(
+        &self,
+        _post_id: &str,
+        _limit: u32,
+        _after: Option<&str>,
+    ) -> Result<RawResponse<Comments>> {
+        unimplemented!()
+    }
+
+    async fn get_comment_replies_raw(
+        &self,
+        _post_id: &str,
+        _comment_id: &str,
+        _limit: u32,
+        _after: Option<&str>,
+    ) -> Result<RawResponse<Comments>> {
+        unimplemented!()
+    }
+}
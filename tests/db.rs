@@ -0,0 +1,93 @@
+#![cfg(feature = "sqlite")]
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::video_list_item_at;
+use rusqlite::Connection;
+use vlive::db;
+use vlive::model::recent_video::RecentVideo;
+
+#[test]
+fn test_videos_between() {
+    let conn = Connection::open_in_memory().unwrap();
+    db::create_schema(&conn).unwrap();
+
+    db::upsert_video_list_item(&conn, "EDBF", &video_list_item_at(1, 2021, 1, 1)).unwrap();
+    db::upsert_video_list_item(&conn, "EDBF", &video_list_item_at(2, 2021, 2, 1)).unwrap();
+    db::upsert_video_list_item(&conn, "FE619", &video_list_item_at(3, 2021, 1, 15)).unwrap();
+
+    // Upserting again updates the existing row
+    let mut updated = video_list_item_at(1, 2021, 1, 1);
+    updated.play_count = 500;
+    db::upsert_video_list_item(&conn, "EDBF", &updated).unwrap();
+
+    let videos = db::get_channel_videos_between(
+        &conn,
+        "EDBF",
+        Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+        Utc.ymd(2021, 2, 1).and_hms(0, 0, 0),
+    )
+    .unwrap();
+
+    assert_eq!(videos.len(), 1);
+    assert_eq!(videos[0].video_seq, 1);
+    assert_eq!(videos[0].play_count, Some(500));
+    assert!(db::get_video(&conn, 4).unwrap().is_none());
+}
+
+#[test]
+fn test_upsert_post() {
+    let conn = Connection::open_in_memory().unwrap();
+    db::create_schema(&conn).unwrap();
+
+    let post = common::post("0-123456");
+    db::upsert_post(&conn, &post).unwrap();
+    db::upsert_post(&conn, &post).unwrap();
+
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM posts", rusqlite::NO_PARAMS, |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_upsert_recent_video() {
+    let conn = Connection::open_in_memory().unwrap();
+    db::create_schema(&conn).unwrap();
+
+    let html = r#"
+        <li>
+            <a class="thumb_area" data-seq="1" data-ga-type="VOD" data-ga-name="Video 1"
+                data-ga-cseq="13" data-ga-cname="BTS" data-ga-ctype="BASIC">
+                <img src="https://phinf.pstatic.net/thumb.jpg?type=f228_128">
+            </a>
+            <div class="video_date">
+                <a class="name" href="/channel/FE619">BTS</a>
+                <span class="date">2 hours ago</span>
+            </div>
+        </li>
+    "#;
+    let now = Utc.ymd(2021, 1, 1).and_hms(13, 0, 0);
+    let recent = RecentVideo::from_html_at(html, now).unwrap();
+    db::upsert_recent_video(&conn, &recent[0]).unwrap();
+
+    // The estimated publish time isn't used as the on air time
+    let video = db::get_video(&conn, 1).unwrap().unwrap();
+    assert_eq!(video.on_air_start_at, None);
+    assert_eq!(
+        video.approx_published_at,
+        Some(Utc.ymd(2021, 1, 1).and_hms(11, 0, 0))
+    );
+
+    db::upsert_video_list_item(&conn, "FE619", &video_list_item_at(1, 2021, 1, 1)).unwrap();
+
+    let video = db::get_video(&conn, 1).unwrap().unwrap();
+    assert_eq!(
+        video.on_air_start_at,
+        Some(Utc.ymd(2021, 1, 1).and_hms(11, 0, 0))
+    );
+    assert!(video.approx_published_at.is_some());
+}
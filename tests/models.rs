@@ -0,0 +1,202 @@
+#[cfg(feature = "extra-fields")]
+#[test]
+fn test_board_extra_fields() {
+    use vlive::model::grouped_board::Board;
+
+    let board: Board = serde_json::from_str(
+        r#"{
+            "boardId": 21,
+            "title": "Notice",
+            "boardType": "NOTICE",
+            "useStarFilter": false,
+            "payRequired": false,
+            "expose": true,
+            "openType": "PUBLIC",
+            "lastUpdatedAt": 1612345678000,
+            "channelCode": "EDBF",
+            "someNewField": "value"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(board.board_id, 21);
+    assert_eq!(board.extra["someNewField"], "value");
+}
+
+#[test]
+fn test_profile_type() {
+    use vlive::model::board_posts::ProfileType;
+
+    assert_eq!(ProfileType::from("STAR"), ProfileType::Star);
+    assert_eq!(ProfileType::from("NONE"), ProfileType::Fan);
+    assert_eq!(
+        ProfileType::from("SOMETHING"),
+        ProfileType::Unknown("SOMETHING".into())
+    );
+}
+
+#[test]
+fn test_photo_file_name() {
+    use vlive::model::board_posts::Photo;
+
+    let photo: Photo = serde_json::from_str(
+        r#"{
+            "url": "https://phinf.pstatic.net/abc/photo.JPEG?type=w1000",
+            "width": 1000,
+            "createdAt": 1612345678000,
+            "height": 800,
+            "photoId": "0-123/456"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        photo.original_url(),
+        "https://phinf.pstatic.net/abc/photo.JPEG"
+    );
+    assert_eq!(photo.file_name(), "1612345678000_0-123_456.jpeg");
+}
+
+#[test]
+fn test_board_post() {
+    use vlive::model::board_posts::Post;
+
+    let post: Post = serde_json::from_str(include_str!("fixtures/board_post.json")).unwrap();
+
+    assert!(post.author.is_star());
+    assert!(post.notice);
+    assert!(post.is_scheduled());
+    assert!(!post.is_video());
+    assert_eq!(post.star_reactions[0].emotion_id, "like");
+    assert_eq!(post.attachments.photo.len(), 1);
+}
+
+#[test]
+fn test_dedup_reposts() {
+    use vlive::model::board_posts::BoardPosts;
+
+    let post: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/board_post.json")).unwrap();
+    let mut repost = post.clone();
+    repost["postId"] = "0-999999".into();
+    repost["originPost"] = serde_json::json!({ "postId": "0-123456" });
+
+    let mut posts: BoardPosts = serde_json::from_value(serde_json::json!({
+        "paging": {},
+        "data": [post, repost],
+    }))
+    .unwrap();
+
+    assert!(posts.data[1].is_repost());
+    assert_eq!(posts.data[1].original_post_id(), "0-123456");
+    assert!(posts.next_after().is_none());
+
+    posts.dedup_reposts();
+    assert_eq!(posts.data.len(), 1);
+}
+
+#[test]
+fn test_malformed_shared_posts() {
+    use vlive::model::board_posts::Post;
+
+    let mut post: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/board_post.json")).unwrap();
+    post["sharedPosts"] = serde_json::json!([
+        { "postId": "0-123" },
+        { "title": "missing post id" },
+        null,
+    ]);
+    post["originPost"] = serde_json::json!({ "postId": 123 });
+
+    let post: Post = serde_json::from_value(post).unwrap();
+
+    assert_eq!(post.shared_posts.len(), 3);
+    assert_eq!(post.shared_posts[0].as_ref().unwrap().post_id, "0-123");
+    assert!(post.shared_posts[1].is_none());
+    assert!(post.shared_posts[2].is_none());
+    assert!(!post.is_repost());
+}
+
+#[test]
+fn test_post_info() {
+    use vlive::model::{board_posts::Post, common::PostInfo};
+
+    fn summary<P: PostInfo>(post: &P) -> String {
+        format!("{} by {}", post.url(), post.author().nickname)
+    }
+
+    let post: Post = serde_json::from_str(include_str!("fixtures/board_post.json")).unwrap();
+
+    assert_eq!(summary(&post), "https://www.vlive.tv/post/0-123456 by Star");
+    assert!(post.official_video().is_none());
+    assert_eq!(post.board.title.as_deref(), Some("Notice"));
+}
+
+#[test]
+fn test_timestamps() {
+    use chrono::{TimeZone, Timelike, Utc};
+    use vlive::{model::board_posts::Post, time::ToKst};
+
+    let post: Post = serde_json::from_str(include_str!("fixtures/board_post.json")).unwrap();
+
+    assert_eq!(post.created_at, Utc.timestamp_millis(1612345678000));
+    assert_eq!(post.created_at.to_kst(), post.created_at);
+    assert_eq!(
+        post.created_at.to_kst().hour(),
+        (post.created_at.hour() + 9) % 24
+    );
+}
+
+#[test]
+fn test_string_enums() {
+    use vlive::model::video::{ProductType, ScreenOrientation, VideoType};
+
+    let kind: VideoType = serde_json::from_str(r#""LIVE""#).unwrap();
+    assert_eq!(kind, VideoType::LIVE);
+
+    let product: ProductType = serde_json::from_str(r#""PAID""#).unwrap();
+    assert!(product.is_paid());
+
+    let orientation: ScreenOrientation = serde_json::from_str(r#""DIAGONAL""#).unwrap();
+    assert_eq!(orientation, ScreenOrientation::Unknown("DIAGONAL".into()));
+    assert!(!orientation.is_vertical());
+    assert_eq!(
+        serde_json::to_string(&orientation).unwrap(),
+        r#""DIAGONAL""#
+    );
+}
+
+#[test]
+fn test_multinational_titles() {
+    use vlive::model::video::OfficialVideo;
+
+    let video: OfficialVideo = serde_json::from_value(serde_json::json!({
+        "videoSeq": 123,
+        "type": "VOD",
+        "title": "원래 제목",
+        "multinationalTitles": [
+            { "type": "TITLE", "seq": 1, "locale": "ja_JP", "label": "日本語のタイトル", "defaultYn": true },
+            { "type": "TITLE", "seq": 2, "locale": "en_US", "label": "English title", "defaultYn": false },
+            { "type": "TITLE", "seq": 3, "locale": "zh_CN", "label": "中文标题" },
+        ],
+        "playCount": 1,
+        "likeCount": 1,
+        "commentCount": 1,
+        "thumb": "https://example.com/thumb.jpg",
+        "exposeStatus": "EXPOSED",
+        "willStartAt": 1612345678000_i64,
+        "onAirStartAt": 1612345678000_i64,
+        "willEndAt": 1612345678000_i64,
+        "createdAt": 1612345678000_i64,
+    }))
+    .unwrap();
+
+    assert_eq!(video.title_for("zh_CN"), "中文标题");
+    assert_eq!(video.title_for("zh_TW"), "中文标题");
+    assert_eq!(video.title_for("ko_KR"), "English title");
+    assert_eq!(video.title_for_locales(&["ko_KR"]), "日本語のタイトル");
+    assert_eq!(
+        video.locales().collect::<Vec<_>>(),
+        vec!["ja_JP", "en_US", "zh_CN"]
+    );
+}
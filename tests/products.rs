@@ -0,0 +1,41 @@
+mod common;
+
+use vlive::model::{channel::VideoListItem, video::ProductType};
+use vlive::products::group_by_package;
+
+fn video(video_seq: u32, product_id: &str, package_product_id: &str) -> VideoListItem {
+    let mut video = common::video_list_item(video_seq);
+    video.product_id = product_id.to_string();
+    video.package_product_id = package_product_id.to_string();
+    video.product_type = if product_id.is_empty() {
+        ProductType::None
+    } else {
+        ProductType::Paid
+    };
+
+    video
+}
+
+#[test]
+fn test_group_by_package() {
+    let packages = group_by_package(vec![
+        video(1, "P1", "PKG1"),
+        video(2, "", ""),
+        video(3, "P3", ""),
+        video(4, "P4", "PKG1"),
+    ]);
+
+    assert_eq!(packages.len(), 2);
+    assert_eq!(packages[0].product_id, "PKG1");
+    assert!(packages[0].is_package());
+    assert_eq!(
+        packages[0]
+            .videos
+            .iter()
+            .map(|v| v.video_seq)
+            .collect::<Vec<_>>(),
+        vec![1, 4]
+    );
+    assert_eq!(packages[1].product_id, "P3");
+    assert!(!packages[1].is_package());
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use vlive::model::{
+    channel::VideoListItem,
+    video::{ProductType, VideoType},
+};
+use vlive::query::VideoQuery;
+
+fn video(
+    video_type: VideoType,
+    title: &str,
+    play_time: u32,
+    product_type: ProductType,
+) -> VideoListItem {
+    let mut video = common::video_list_item_at(1, 2021, 1, 1);
+    video.video_type = video_type;
+    video.title = title.to_string();
+    video.play_time = play_time;
+    video.product_type = product_type;
+
+    video
+}
+
+#[test]
+fn test_video_query_matches() {
+    let query = VideoQuery::new()
+        .video_type(VideoType::LIVE)
+        .from(Utc.ymd(2021, 1, 1).and_hms(0, 0, 0))
+        .to(Utc.ymd(2021, 1, 2).and_hms(0, 0, 0))
+        .paid(false)
+        .min_play_time(600)
+        .title("(?i)run")
+        .unwrap();
+
+    assert!(query.matches(&video(VideoType::LIVE, "Run BTS!", 3600, ProductType::None)));
+    assert!(!query.matches(&video(VideoType::VOD, "Run BTS!", 3600, ProductType::None)));
+    assert!(!query.matches(&video(VideoType::LIVE, "Run BTS!", 60, ProductType::None)));
+    assert!(!query.matches(&video(VideoType::LIVE, "Run BTS!", 3600, ProductType::Paid)));
+    assert!(!query.matches(&video(VideoType::LIVE, "BTS Live", 3600, ProductType::None)));
+
+    let later = VideoQuery::new().from(Utc.ymd(2021, 1, 2).and_hms(0, 0, 0));
+    assert!(!later.matches(&video(VideoType::LIVE, "Run BTS!", 3600, ProductType::None)));
+}
+
+#[test]
+fn test_video_query_invalid_title() {
+    assert!(VideoQuery::new().title("(unclosed").is_err());
+}
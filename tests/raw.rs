@@ -0,0 +1,34 @@
+use reqwest::{Response, StatusCode};
+use vlive::model::board_posts::Post;
+use vlive::{raw, RawResponse};
+
+fn response(status: u16, body: &str) -> Response {
+    http::Response::builder()
+        .status(status)
+        .body(body.to_string())
+        .unwrap()
+        .into()
+}
+
+#[tokio::test]
+async fn test_raw_response_keeps_body_on_parse_error() {
+    let body = r#"{"errorCode":"common_404","message":"Not Found"}"#;
+    let raw: RawResponse<Post> = RawResponse::from_response(response(404, body), raw::json)
+        .await
+        .unwrap();
+
+    assert_eq!(raw.status, StatusCode::NOT_FOUND);
+    assert_eq!(raw.body, body);
+    assert!(raw.value.is_err());
+}
+
+#[tokio::test]
+async fn test_raw_response_parses_body() {
+    let body = include_str!("fixtures/board_post.json");
+    let raw: RawResponse<Post> = RawResponse::from_response(response(200, body), raw::json)
+        .await
+        .unwrap();
+
+    assert_eq!(raw.body, body);
+    assert_eq!(raw.into_value().unwrap().post_id, "0-123456");
+}
@@ -0,0 +1,54 @@
+use vlive::model::member::Member;
+use vlive::session::{parse_cookie_file, Cookie};
+use vlive::Session;
+
+const COOKIES: &str = "# Netscape HTTP Cookie File
+.vlive.tv\tTRUE\t/\tFALSE\t0\tNEO_SES\tsession
+#HttpOnly_.naver.com\tTRUE\t/\tTRUE\t4102444800\tNID_AUT\tauth
+.naver.com\tTRUE\t/\tTRUE\t946684800\tNID_SES\texpired
+.example.com\tTRUE\t/\tFALSE\t0\tother\tvalue
+malformed line
+";
+
+#[test]
+fn test_parse_cookie_file() {
+    let cookies = parse_cookie_file(COOKIES);
+
+    assert_eq!(cookies.len(), 4);
+    assert_eq!(cookies[0].name, "NEO_SES");
+    assert!(cookies[0].expires.is_none());
+    assert!(cookies[1].secure);
+    assert!(cookies[2].is_expired());
+}
+
+#[test]
+fn test_parse_empty_cookie_value() {
+    let cookies = parse_cookie_file(".vlive.tv\tTRUE\t/\tTRUE\t0\tempty\t\r\n");
+
+    assert_eq!(cookies.len(), 1);
+    assert_eq!(cookies[0].name, "empty");
+    assert_eq!(cookies[0].value, "");
+    assert!(Cookie::vlive("NEO_SES", "session").secure);
+}
+
+#[test]
+fn test_session_from_cookies() {
+    let session = Session::from_cookies(parse_cookie_file(COOKIES)).unwrap();
+
+    assert_eq!(session.cookie_names(), ["NEO_SES", "NID_AUT"]);
+    assert!(session.has_session_cookie());
+    assert!(!format!("{:?}", session).contains("session\""));
+
+    let session = Session::from_cookie_header("NID_AUT=auth; NID_SES=ses").unwrap();
+    assert!(!session.has_session_cookie());
+
+    assert!(Session::from_cookie_header("").is_err());
+}
+
+#[test]
+fn test_member() {
+    let member: Member = serde_json::from_str(include_str!("fixtures/member.json")).unwrap();
+
+    assert_eq!(member.member_id, "ABC123");
+    assert_eq!(member.country_code.as_deref(), Some("US"));
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use vlive::model::{channel::VideoListItem, video::VideoType};
+use vlive::stats::{longest_videos, ChannelStats};
+
+fn video(
+    video_seq: u32,
+    video_type: VideoType,
+    play_time: u32,
+    month: u32,
+    day: u32,
+) -> VideoListItem {
+    let mut video = common::video_list_item_at(video_seq, 2021, month, day);
+    video.video_type = video_type;
+    video.play_time = play_time;
+    video.play_count = 100;
+    video.like_count = 2000;
+
+    video
+}
+
+#[test]
+fn test_channel_stats() {
+    let mut upcoming = video(4, VideoType::LIVE, 0, 2, 1);
+    upcoming.upcoming_yn = true;
+
+    let videos = vec![
+        video(1, VideoType::LIVE, 3600, 1, 1),
+        video(2, VideoType::VOD, 120, 1, 8),
+        video(3, VideoType::VOD, 600, 1, 15),
+        upcoming,
+    ];
+    let stats = ChannelStats::from_videos(&videos);
+
+    assert_eq!(stats.video_count, 3);
+    assert_eq!(stats.live_count, 1);
+    assert_eq!(stats.total_plays, 300);
+    assert_eq!(stats.average_likes(), 2000.0);
+    assert_eq!(stats.longest_play_time, 3600);
+    assert_eq!(stats.uploads_per_week(), Some(1.0));
+    assert!((stats.live_ratio() - 1.0 / 3.0).abs() < f64::EPSILON);
+
+    let longest: Vec<_> = longest_videos(&videos, 2)
+        .iter()
+        .map(|v| v.video_seq)
+        .collect();
+    assert_eq!(longest, vec![1, 3]);
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::{board, post, video_list_item, MockRequester};
+use vlive::sync::{sync_channel, SyncReport, SyncState};
+use vlive::ChannelId;
+
+#[test]
+fn test_update_videos() {
+    let mut state = SyncState::new("FE619");
+    let mut report = SyncReport::default();
+    state.update_videos(vec![video_list_item(1), video_list_item(2)], &mut report);
+
+    assert_eq!(report.new_videos.len(), 2);
+    assert!(report.updated_videos.is_empty());
+
+    let mut updated = video_list_item(2);
+    updated.play_count += 1;
+
+    let mut report = SyncReport::default();
+    state.update_videos(vec![updated, video_list_item(3)], &mut report);
+
+    assert_eq!(report.new_videos.len(), 1);
+    assert_eq!(report.new_videos[0].video_seq, 3);
+    assert_eq!(report.updated_videos.len(), 1);
+    assert_eq!(report.updated_videos[0].video.video_seq, 2);
+    assert_eq!(
+        report.updated_videos[0].previous.play_count + 1,
+        report.updated_videos[0].video.play_count
+    );
+    assert_eq!(report.removed_videos, vec![1]);
+    assert_eq!(state.videos.len(), 2);
+}
+
+#[test]
+fn test_update_board() {
+    let board = board(21);
+    let mut state = SyncState::new("FE619");
+    let mut report = SyncReport::default();
+    state.update_board(&board, vec![post("0-1"), post("0-2")], &mut report);
+
+    assert_eq!(report.new_posts.len(), 2);
+
+    let mut updated = post("0-2");
+    updated.comment_count += 1;
+
+    let mut report = SyncReport::default();
+    state.update_board(&board, vec![updated, post("0-2")], &mut report);
+
+    assert!(report.new_posts.is_empty());
+    assert_eq!(report.updated_posts.len(), 1);
+    assert_eq!(report.updated_posts[0].post.post_id, "0-2");
+    assert_eq!(report.removed_posts, vec!["0-1"]);
+    assert_eq!(state.boards[&21].posts.len(), 1);
+}
+
+#[tokio::test]
+async fn test_sync_failure_keeps_state() {
+    let mut mock = MockRequester {
+        videos: (1..=3).map(video_list_item).collect(),
+        boards: vec![board(21)],
+        fail_on: Some("posts 21 after=".to_string()),
+        ..Default::default()
+    };
+    mock.posts.insert(21, vec![post("0-1")]);
+
+    let channel = ChannelId::from_code("FE619");
+    let mut state = SyncState::new("FE619");
+
+    assert!(sync_channel(&mock, &channel, &mut state, 2).await.is_err());
+    assert!(state.videos.is_empty());
+    assert!(state.last_synced_at.is_none());
+
+    mock.fail_on = None;
+    let report = sync_channel(&mock, &channel, &mut state, 2).await.unwrap();
+
+    assert_eq!(report.new_videos.len(), 3);
+    assert_eq!(report.new_posts.len(), 1);
+    assert_eq!(state.videos.len(), 3);
+    assert!(state.last_synced_at.is_some());
+
+    // Nothing changed and the board wasn't updated since the last sync
+    let report = sync_channel(&mock, &channel, &mut state, 2).await.unwrap();
+
+    assert!(report.is_empty());
+    assert_eq!(report.skipped_boards, vec![21]);
+}
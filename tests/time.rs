@@ -0,0 +1,84 @@
+use chrono::{Duration, TimeZone, Utc};
+use vlive::model::recent_video::RecentVideo;
+use vlive::time::{kst, parse_posted_age};
+
+#[test]
+fn test_parse_relative_posted_age() {
+    let now = Utc.ymd(2021, 3, 10).and_hms(12, 0, 0);
+
+    assert_eq!(parse_posted_age("just now", now), Some(now));
+    assert_eq!(
+        parse_posted_age("3 hours ago", now),
+        Some(now - Duration::hours(3))
+    );
+    assert_eq!(
+        parse_posted_age("an hour ago", now),
+        Some(now - Duration::hours(1))
+    );
+    assert_eq!(
+        parse_posted_age("2 days ago", now),
+        Some(now - Duration::days(2))
+    );
+    assert_eq!(
+        parse_posted_age("5분 전", now),
+        Some(now - Duration::minutes(5))
+    );
+    assert_eq!(
+        parse_posted_age("3시간 전", now),
+        Some(now - Duration::hours(3))
+    );
+    assert_eq!(parse_posted_age("방금 전", now), Some(now));
+    assert_eq!(parse_posted_age("sometime", now), None);
+}
+
+#[test]
+fn test_parse_overflowing_posted_age() {
+    let now = Utc.ymd(2021, 3, 10).and_hms(12, 0, 0);
+
+    assert_eq!(parse_posted_age("2000000000 years ago", now), None);
+    assert_eq!(
+        parse_posted_age("9223372036854775807 seconds ago", now),
+        None
+    );
+    assert_eq!(parse_posted_age("2000000000년 전", now), None);
+    assert_eq!(parse_posted_age("99999999999999999999년 전", now), None);
+}
+
+#[test]
+fn test_parse_absolute_posted_age() {
+    let now = Utc.ymd(2021, 3, 10).and_hms(12, 0, 0);
+    let jan_2 = kst().ymd(2021, 1, 2).and_hms(0, 0, 0).with_timezone(&Utc);
+
+    assert_eq!(parse_posted_age("Jan 2, 2021", now), Some(jan_2));
+    assert_eq!(parse_posted_age("2021.01.02.", now), Some(jan_2));
+    assert_eq!(parse_posted_age("Jan 2", now), Some(jan_2));
+    assert_eq!(parse_posted_age("1월 2일", now), Some(jan_2));
+
+    // Later in the year than now, so it must be from last year
+    let dec_2 = kst().ymd(2020, 12, 2).and_hms(0, 0, 0).with_timezone(&Utc);
+    assert_eq!(parse_posted_age("Dec 2", now), Some(dec_2));
+}
+
+#[test]
+fn test_recent_video_published_at() {
+    let html = r#"
+        <li>
+            <a class="thumb_area" data-seq="232024" data-ga-type="VOD" data-ga-name="Title"
+                data-ga-cseq="13" data-ga-cname="BTS" data-ga-ctype="BASIC">
+                <img src="https://phinf.pstatic.net/thumb.jpg?type=f228_128">
+                <span class="time">10:20</span>
+            </a>
+            <div class="video_date">
+                <a class="name" href="/channel/FE619">BTS</a>
+                <span class="date">2 hours ago</span>
+            </div>
+        </li>
+    "#;
+    let now = Utc.ymd(2021, 3, 10).and_hms(12, 0, 0);
+
+    let videos = RecentVideo::from_html_at(html, now).unwrap();
+
+    assert_eq!(videos.len(), 1);
+    assert_eq!(videos[0].posted_age.as_deref(), Some("2 hours ago"));
+    assert_eq!(videos[0].published_at, Some(now - Duration::hours(2)));
+}
@@ -0,0 +1,37 @@
+use chrono::{Duration, TimeZone, Utc};
+use vlive::tracker::{CountSample, Tracker};
+
+fn sample(hour: u32, play_count: i64) -> CountSample {
+    CountSample {
+        sampled_at: Utc.ymd(2021, 1, 1).and_hms(hour, 0, 0),
+        play_count,
+        like_count: play_count * 10,
+        comment_count: 0,
+    }
+}
+
+#[test]
+fn test_growth_rates() {
+    let mut tracker = Tracker::new();
+    tracker.track(123);
+    // Out of order and untracked samples
+    tracker.record(123, sample(2, 300));
+    tracker.record(123, sample(0, 100));
+    tracker.record(123, sample(1, 150));
+    tracker.record(456, sample(0, 100));
+
+    let series = tracker.series(123).unwrap();
+    let rates = series.rates();
+
+    assert!(tracker.series(456).is_none());
+    assert_eq!(series.latest().unwrap().play_count, 300);
+    assert_eq!(rates.len(), 2);
+    assert_eq!(rates[0].plays_per_hour, 50.0);
+    assert_eq!(rates[1].plays_per_hour, 150.0);
+    assert_eq!(rates[1].likes_per_hour, 1500.0);
+    assert_eq!(series.overall().unwrap().plays_per_hour, 100.0);
+    assert_eq!(
+        series.recent(Duration::hours(1)).unwrap().plays_per_hour,
+        150.0
+    );
+}
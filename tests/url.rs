@@ -0,0 +1,57 @@
+use vlive::VLiveUrl;
+
+#[test]
+fn test_parse_urls() {
+    assert_eq!(
+        VLiveUrl::parse("https://www.vlive.tv/video/123456").unwrap(),
+        VLiveUrl::Video { video_seq: 123456 }
+    );
+    assert_eq!(
+        VLiveUrl::parse("https://www.vlive.tv/channel/FE619/board/21").unwrap(),
+        VLiveUrl::Board {
+            channel_code: "FE619".into(),
+            board_id: 21
+        }
+    );
+    assert_eq!(
+        VLiveUrl::parse("https://www.vlive.tv/channel/FE619").unwrap(),
+        VLiveUrl::Channel {
+            channel_code: "FE619".into()
+        }
+    );
+    assert_eq!(
+        VLiveUrl::parse("http://channels.vlive.tv/FE619").unwrap(),
+        VLiveUrl::Channel {
+            channel_code: "FE619".into()
+        }
+    );
+    assert_eq!(
+        VLiveUrl::parse("https://www.vlive.tv/post/0-12345").unwrap(),
+        VLiveUrl::Post {
+            post_id: "0-12345".into()
+        }
+    );
+    assert!(VLiveUrl::parse("https://example.com/video/1").is_err());
+}
+
+#[test]
+fn test_parse_url_boundaries() {
+    assert_eq!(
+        VLiveUrl::parse("https://www.vlive.tv/video/123456?channelCode=FE619").unwrap(),
+        VLiveUrl::Video { video_seq: 123456 }
+    );
+    assert_eq!(
+        VLiveUrl::parse("https://www.vlive.tv/post/0-12345#comments").unwrap(),
+        VLiveUrl::Post {
+            post_id: "0-12345".into()
+        }
+    );
+    assert!(VLiveUrl::parse("https://www.vlive.tv/video/123abc").is_err());
+    assert!(VLiveUrl::parse("https://www.vlive.tv/post/0-12345abc").is_err());
+}
+
+#[test]
+fn test_url_round_trip() {
+    let url = "https://www.vlive.tv/channel/FE619/board/21";
+    assert_eq!(url.parse::<VLiveUrl>().unwrap().to_string(), url);
+}
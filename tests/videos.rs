@@ -31,6 +31,20 @@ async fn test_get_recent_videos_detail() {
     }
 }
 
+#[tokio::test]
+async fn test_enrich_recent_videos() {
+    let client = Client::new();
+    let videos = client.get_recent_videos(12, 1).await.unwrap();
+    let seqs: Vec<_> = videos.iter().map(|v| v.video_seq).collect();
+
+    let enriched = vlive::enrich::enrich_all(&client, videos, 4).await;
+
+    for (video, seq) in enriched.into_iter().zip(seqs) {
+        let video = video.expect("Enrich video");
+        assert_eq!(video.video.video_seq as u64, seq);
+    }
+}
+
 #[tokio::test]
 async fn test_get_video() {
     let client = Client::new();
@@ -73,6 +87,18 @@ async fn test_get_video_streams_chplus() {
     assert_eq!(video.meta.url, "http://vlive.tv/video/233176");
 }
 
+#[tokio::test]
+async fn test_get_video_comments() {
+    let client = Client::new();
+    let video = client.get_video(232024).await.unwrap();
+    let post_id = &video.post_detail.get_detail().expect("Has detail").post_id;
+
+    let comments = client.get_comments(post_id, 20, None).await.unwrap();
+
+    println!("Found comments: {:#?}", comments);
+    assert!(!comments.data.is_empty());
+}
+
 // ignore this test as needs a video that's live to pass
 /*
 #[ignore]